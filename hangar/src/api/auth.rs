@@ -0,0 +1,327 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use super::error::{error_for_status, HangarError, MaybeUnauthorized};
+use log::{debug, info};
+use reqwest::header::{HeaderMap, HeaderValue, InvalidHeaderValue, AUTHORIZATION};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+/// How long before it expires a JWT gets refreshed, so that
+/// it doesn't run out in the middle of a request
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error(transparent)]
+    Hangar(#[from] HangarError),
+
+    #[error("hangar returned a token that can't be sent in a header: {0}")]
+    InvalidToken(#[from] InvalidHeaderValue),
+}
+
+impl From<reqwest::Error> for AuthError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl MaybeUnauthorized for AuthError {
+    fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Hangar(err) if err.is_unauthorized())
+    }
+}
+
+impl From<AuthError> for HangarError {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::Hangar(err) => err,
+            AuthError::InvalidToken(err) => Self::InvalidToken(err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A JWT returned by Hangar in exchange for an API key
+pub struct HangarSession {
+    pub token: String,
+    /// How long the token stays valid, in milliseconds
+    pub expires_in: u64,
+}
+
+/// Exchanges `api_key` for a JWT. Most callers want an
+/// `AuthenticatedClient`, which does this on its own
+pub async fn authenticate(client: &Client, api_key: &str) -> Result<HangarSession, AuthError> {
+    info!("Authenticating with Hangar");
+
+    let response = client
+        .post(format!("{}/api/v1/authenticate", super::HANGAR_ENDPOINT))
+        .query(&[("apiKey", api_key)])
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+struct CachedClient {
+    client: Client,
+    expires_at: Instant,
+}
+
+impl CachedClient {
+    fn is_fresh(&self, now: Instant) -> bool {
+        now + REFRESH_MARGIN < self.expires_at
+    }
+}
+
+/// Keeps a JWT for an API key around, refreshing it when it's about
+/// to expire, and hands out `Client`s that send it with every request.
+///
+/// The `Client` given to `request` works with every function in this
+/// crate, which makes private projects reachable through them
+/// ## Usage
+/// ```no_run
+/// use hangar::{get_versions, AuthenticatedClient, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let key = std::env::var("HANGAR_API_KEY").unwrap();
+///     let auth = AuthenticatedClient::new(key);
+///
+///     let vquery = &VersionQueryBuilder::default().build();
+///     let versions = auth
+///         .request(|client| async move {
+///             get_versions(&client, "MyPrivatePlugin", vquery).await
+///         })
+///         .await
+///         .unwrap();
+///
+///     println!("{} versions", versions.result.len());
+/// }
+/// ```
+pub struct AuthenticatedClient {
+    api_key: String,
+    http: Client,
+    cached: Mutex<Option<CachedClient>>,
+}
+
+impl AuthenticatedClient {
+    pub fn new<T: ToString>(api_key: T) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+            http: Client::new(),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Runs `request` with a `Client` from `client`. When Hangar rejects the
+    /// JWT anyway, because it was revoked or expired early, it is dropped and
+    /// `request` runs once more with a freshly authenticated `Client`
+    pub async fn request<T, E, F, Fut>(&self, request: F) -> Result<T, E>
+    where
+        F: FnMut(Client) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<AuthError> + MaybeUnauthorized,
+    {
+        self.request_with(request, || authenticate(&self.http, &self.api_key))
+            .await
+    }
+
+    /// `request`, getting a new JWT from `login` when one is needed
+    async fn request_with<T, E, F, Fut, L, LFut>(
+        &self,
+        mut request: F,
+        mut login: L,
+    ) -> Result<T, E>
+    where
+        F: FnMut(Client) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<AuthError> + MaybeUnauthorized,
+        L: FnMut() -> LFut,
+        LFut: Future<Output = Result<HangarSession, AuthError>>,
+    {
+        match request(self.client_with(&mut login).await?).await {
+            Err(err) if err.is_unauthorized() => {
+                debug!("Hangar rejected the JWT, authenticating again");
+                self.invalidate().await;
+
+                request(self.client_with(&mut login).await?).await
+            }
+            result => result,
+        }
+    }
+
+    /// A `Client` sending a valid JWT with every request, authenticating
+    /// again if the last one is about to expire. Nothing retries the
+    /// requests made with it if the JWT gets rejected, see `request` for that
+    pub async fn client(&self) -> Result<Client, AuthError> {
+        self.client_with(|| authenticate(&self.http, &self.api_key))
+            .await
+    }
+
+    /// `client`, getting a new JWT from `login` when one is needed
+    async fn client_with<L, LFut>(&self, login: L) -> Result<Client, AuthError>
+    where
+        L: FnOnce() -> LFut,
+        LFut: Future<Output = Result<HangarSession, AuthError>>,
+    {
+        let mut cached = self.cached.lock().await;
+
+        if let Some(cached) = cached.as_ref().filter(|c| c.is_fresh(Instant::now())) {
+            return Ok(cached.client.clone());
+        }
+
+        debug!("Hangar JWT missing or about to expire, refreshing");
+        let requested_at = Instant::now();
+        let session = login().await?;
+
+        let mut value = HeaderValue::from_str(&format!("HangarAuth {}", session.token))?;
+        value.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, value);
+        let client = Client::builder().default_headers(headers).build()?;
+
+        *cached = Some(CachedClient {
+            client: client.clone(),
+            expires_at: requested_at + Duration::from_millis(session.expires_in),
+        });
+
+        Ok(client)
+    }
+
+    /// Drops the current JWT, so that the next call to `client` authenticates again
+    pub async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+}
+
+impl std::fmt::Debug for AuthenticatedClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthenticatedClient")
+            .field("api_key", &"<redacted>")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+    use std::cell::Cell;
+
+    #[test]
+    fn session() {
+        let raw = r#"{ "token": "eyJhbGciOiJIUzI1NiJ9.e30.abc", "expiresIn": 10800000 }"#;
+        let session: HangarSession = from_str(raw).unwrap();
+
+        assert_eq!(session.expires_in, 10_800_000);
+    }
+
+    #[test]
+    fn refreshes_before_expiry() {
+        let now = Instant::now();
+        let cached = |expires_in| CachedClient {
+            client: Client::new(),
+            expires_at: now + expires_in,
+        };
+
+        assert!(cached(Duration::from_secs(3600)).is_fresh(now));
+        assert!(!cached(Duration::from_secs(10)).is_fresh(now));
+    }
+
+    fn with_fresh_client(auth: &AuthenticatedClient) {
+        *auth.cached.try_lock().unwrap() = Some(CachedClient {
+            client: Client::new(),
+            expires_at: Instant::now() + Duration::from_secs(3600),
+        });
+    }
+
+    #[tokio::test]
+    async fn request_keeps_fresh_jwt() {
+        let auth = AuthenticatedClient::new("key");
+        with_fresh_client(&auth);
+        let calls = Cell::new(0);
+
+        let result = auth
+            .request(|_| async {
+                calls.set(calls.get() + 1);
+                Err::<(), _>(HangarError::NotFound("project".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(HangarError::NotFound(_))));
+        assert_eq!(calls.get(), 1);
+        assert!(auth.cached.lock().await.is_some());
+    }
+
+    fn fake_session(token: &str) -> HangarSession {
+        HangarSession {
+            token: token.to_string(),
+            expires_in: 10_800_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn request_drops_rejected_jwt() {
+        let auth = AuthenticatedClient::new("key");
+        with_fresh_client(&auth);
+        let calls = Cell::new(0);
+        let logins = Cell::new(0);
+
+        let result = auth
+            .request_with(
+                |_| async {
+                    calls.set(calls.get() + 1);
+                    match calls.get() {
+                        1 => Err(HangarError::Unauthorized("expired".to_string())),
+                        _ => Ok(()),
+                    }
+                },
+                || async {
+                    logins.set(logins.get() + 1);
+                    Ok(fake_session("eyJhbGciOiJIUzI1NiJ9.e30.abc"))
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.get(), 2);
+        assert_eq!(logins.get(), 1);
+        assert!(auth.cached.lock().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn malformed_jwt_is_not_retried() {
+        let auth = AuthenticatedClient::new("key");
+        let calls = Cell::new(0);
+        let logins = Cell::new(0);
+
+        let result = auth
+            .request_with(
+                |_| async {
+                    calls.set(calls.get() + 1);
+                    Ok::<_, HangarError>(())
+                },
+                || async {
+                    logins.set(logins.get() + 1);
+                    Ok(fake_session("not\na header"))
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(HangarError::InvalidToken(_))));
+        assert_eq!(calls.get(), 0);
+        assert_eq!(logins.get(), 1);
+    }
+
+    #[test]
+    fn debug_hides_key() {
+        let auth = AuthenticatedClient::new("secret-key");
+
+        assert!(!format!("{:?}", auth).contains("secret-key"));
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::pin::pin;
+use std::rc::Rc;
+
+use super::auth::AuthError;
+use super::error::{HangarError, MaybeUnauthorized};
+use super::pagination::get_versions_stream;
+use crate::types::query::VersionQueryBuilder;
+use crate::types::version::HangarVersion;
+use crate::types::{HangarPlatform, HangarProjectMeta};
+use futures_util::{Stream, TryStreamExt};
+use log::{debug, info};
+use reqwest::Client;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DependencyError {
+    #[error(transparent)]
+    Hangar(#[from] HangarError),
+
+    #[error("no version of {0} is compatible with the target platform")]
+    NoCompatibleVersion(Rc<str>),
+
+    #[error("dependency cycle involving {0}")]
+    Cycle(Rc<str>),
+}
+
+impl From<AuthError> for DependencyError {
+    fn from(err: AuthError) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl MaybeUnauthorized for DependencyError {
+    fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Hangar(err) if err.is_unauthorized())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+/// Options that change how `resolve_dependencies` picks versions
+pub struct ResolveOptions {
+    /// The platform the plugins will run on
+    pub platform: HangarPlatform,
+    /// The version of `platform` the plugins will run on. When `None`, every
+    /// dependency, however deep, only needs to share one platform version with
+    /// the root version passed to `resolve_dependencies`, not with its parent
+    pub platform_version: Option<Rc<str>>,
+    /// Also consider versions from hidden channels
+    pub include_hidden_channels: bool,
+}
+
+#[derive(Debug, Clone)]
+/// A version of a Hangar project to install
+pub struct LockedPlugin {
+    /// The slug of the project this version is for
+    pub project: Rc<str>,
+    /// The version to install
+    pub version: HangarVersion,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A required dependency hosted outside of Hangar, which
+/// has to be installed by hand
+pub struct ExternalDependency {
+    /// The name of the plugin
+    pub name: Rc<str>,
+    /// Where the plugin can be found
+    pub url: Rc<str>,
+}
+
+#[derive(Debug, Default)]
+/// What `resolve_dependencies` found
+pub struct ResolvedPlugins {
+    /// The versions to install, where dependencies always come
+    /// before their dependents and the resolved project comes last
+    pub plugins: Vec<LockedPlugin>,
+    /// Required dependencies that aren't hosted on Hangar
+    pub external: Vec<ExternalDependency>,
+}
+
+/// Resolves the required dependencies of `version` of `project` on
+/// `options.platform`. Optional dependencies are left out.
+///
+/// Dependencies hosted on Hangar are looked up by name, and the newest of
+/// their versions compatible with the target platform versions is picked,
+/// going through as many pages of versions as needed. The targets are
+/// `options.platform_version` when set, and otherwise every platform version
+/// `version` runs on, since the server has to run the root plugin whatever its
+/// dependencies are. Dependencies hosted elsewhere can't be resolved and are
+/// listed in `ResolvedPlugins::external` instead
+/// ## Usage
+/// ```
+/// use hangar::types::HangarPlatform;
+/// use hangar::{get_versions, resolve_dependencies, ResolveOptions, VersionQueryBuilder};
+/// use reqwest::Client;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new();
+///
+///     let vquery = VersionQueryBuilder::default()
+///         .platform(HangarPlatform::Paper)
+///         .build();
+///     let versions = get_versions(&client, "Maintenance", &vquery).await.unwrap();
+///     let version = versions.result.first().unwrap();
+///
+///     let options = ResolveOptions {
+///         platform: HangarPlatform::Paper,
+///         ..Default::default()
+///     };
+///     let resolved = resolve_dependencies(&client, "Maintenance", version, &options)
+///         .await
+///         .unwrap();
+///
+///     assert_eq!(resolved.plugins.last().unwrap().version.name, version.name);
+/// }
+/// ```
+#[expect(private_bounds)]
+pub async fn resolve_dependencies<M>(
+    client: &Client,
+    project: &M,
+    version: &HangarVersion,
+    options: &ResolveOptions,
+) -> Result<ResolvedPlugins, DependencyError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let slug = project.slug();
+    info!("Resolving dependencies for {} {}", slug, version.name);
+
+    let targets = match &options.platform_version {
+        Some(platform_version) => vec![platform_version.clone()],
+        None => version.platform_versions(options.platform).to_vec(),
+    };
+
+    let mut query = VersionQueryBuilder::default()
+        .platform(options.platform)
+        .include_hidden_channels(options.include_hidden_channels);
+    if let Some(platform_version) = &options.platform_version {
+        query = query.platform_version(platform_version.clone());
+    }
+    let query = query.build();
+
+    resolve_with(slug, version, options, &targets, |name| {
+        get_versions_stream(client, name, &query)
+    })
+    .await
+}
+
+/// Does the work of `resolve_dependencies`, asking `versions_of`
+/// for the versions of each dependency by its name
+async fn resolve_with<F, S>(
+    slug: Rc<str>,
+    version: &HangarVersion,
+    options: &ResolveOptions,
+    targets: &[Rc<str>],
+    mut versions_of: F,
+) -> Result<ResolvedPlugins, DependencyError>
+where
+    F: FnMut(&str) -> S,
+    S: Stream<Item = Result<HangarVersion, HangarError>>,
+{
+    let mut resolution = Resolution::default();
+    let mut queue = VecDeque::from(resolution.add(slug, version.clone(), options.platform));
+
+    while let Some(name) = queue.pop_front() {
+        if resolution.plugins.contains_key(&name.to_lowercase()) {
+            debug!("{} already resolved", name);
+            continue;
+        }
+
+        let picked = pick_version(versions_of(&name), options.platform, targets)
+            .await?
+            .ok_or_else(|| DependencyError::NoCompatibleVersion(name.clone()))?;
+        info!("{} resolved to {}", name, picked.name);
+
+        queue.extend(resolution.add(name, picked, options.platform));
+    }
+
+    let resolved = resolution.into_install_order()?;
+
+    info!("All dependencies resolved!");
+    Ok(resolved)
+}
+
+/// The first of `versions` that can be downloaded for `platform` and runs on
+/// one of the `targets` platform versions. Hangar lists versions newest first,
+/// and no more of `versions` is pulled once one is found
+async fn pick_version<S, E>(
+    versions: S,
+    platform: HangarPlatform,
+    targets: &[Rc<str>],
+) -> Result<Option<HangarVersion>, E>
+where
+    S: Stream<Item = Result<HangarVersion, E>>,
+{
+    let mut versions = pin!(versions);
+
+    while let Some(version) = versions.try_next().await? {
+        let compatible = version.download_for(platform).is_some()
+            && (targets.is_empty()
+                || version
+                    .platform_versions(platform)
+                    .iter()
+                    .any(|supported| targets.contains(supported)));
+
+        if compatible {
+            return Ok(Some(version));
+        }
+    }
+
+    Ok(None)
+}
+
+/// A picked version and the lowercased names of the plugins it needs
+struct Picked {
+    project: Rc<str>,
+    version: HangarVersion,
+    needs: HashSet<String>,
+}
+
+#[derive(Default)]
+/// The plugins picked so far, keyed by their lowercased project
+/// name, since dependencies don't always match the case of the slug
+struct Resolution {
+    plugins: HashMap<String, Picked>,
+    external: Vec<ExternalDependency>,
+}
+
+impl Resolution {
+    /// Records `version` of `project` and the plugins hosted elsewhere it
+    /// needs, returning the names of the ones on Hangar it needs
+    fn add(
+        &mut self,
+        project: Rc<str>,
+        version: HangarVersion,
+        platform: HangarPlatform,
+    ) -> Vec<Rc<str>> {
+        let mut needs = HashSet::new();
+        let mut on_hangar = Vec::new();
+
+        for dependency in version.plugin_dependencies_for(platform) {
+            if !dependency.required {
+                debug!("Skipping optional dependency {}", dependency.name);
+                continue;
+            }
+
+            let Some(url) = &dependency.external_url else {
+                needs.insert(dependency.name.to_lowercase());
+                on_hangar.push(dependency.name.clone());
+                continue;
+            };
+
+            let known = self
+                .external
+                .iter()
+                .any(|known| known.name.eq_ignore_ascii_case(&dependency.name));
+            if !known {
+                info!("{} is hosted at {}", dependency.name, url);
+                self.external.push(ExternalDependency {
+                    name: dependency.name.clone(),
+                    url: url.clone(),
+                });
+            }
+        }
+
+        self.plugins.insert(
+            project.to_lowercase(),
+            Picked {
+                project,
+                version,
+                needs,
+            },
+        );
+
+        on_hangar
+    }
+
+    /// Lists the plugins in rounds, each round holding the plugins whose
+    /// needs were all met by the rounds before it. Every plugin is a
+    /// dependency of the root one, which therefore ends up alone in the
+    /// last round. Plugins left over once no round can be formed need
+    /// each other
+    fn into_install_order(mut self) -> Result<ResolvedPlugins, DependencyError> {
+        let mut installed = HashSet::new();
+        let mut plugins = Vec::with_capacity(self.plugins.len());
+
+        while !self.plugins.is_empty() {
+            let mut round = self
+                .plugins
+                .iter()
+                .filter(|(_, picked)| picked.needs.is_subset(&installed))
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            if round.is_empty() {
+                return Err(DependencyError::Cycle(self.cycle_member(&installed)));
+            }
+            round.sort();
+
+            for key in round {
+                if let Some(picked) = self.plugins.remove(&key) {
+                    plugins.push(LockedPlugin {
+                        project: picked.project,
+                        version: picked.version,
+                    });
+                }
+                installed.insert(key);
+            }
+        }
+
+        Ok(ResolvedPlugins {
+            plugins,
+            external: self.external,
+        })
+    }
+
+    /// A plugin on a cycle among the ones left over by `into_install_order`.
+    /// Each of them needs another one of them, so following those needs
+    /// from any of them eventually comes back to a plugin already seen
+    fn cycle_member(&self, installed: &HashSet<String>) -> Rc<str> {
+        let mut seen = HashSet::new();
+        let mut key = self.plugins.keys().min();
+
+        while let Some(current) = key.filter(|current| seen.insert(*current)) {
+            key = self.plugins[current]
+                .needs
+                .iter()
+                .filter(|need| !installed.contains(*need))
+                .min();
+        }
+
+        key.and_then(|key| self.plugins.get(key))
+            .map(|picked| picked.project.clone())
+            .unwrap_or_else(|| "unknown".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_util::{dependency, version};
+    use crate::get_versions;
+    use futures_util::stream;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn picks_compatible_version() {
+        // More versions than fit on one page, the compatible one being last
+        let mut versions = (0..30)
+            .map(|i| Ok::<_, ()>(version(&format!("3.{}", i), &["1.21"], json!([]))))
+            .collect::<Vec<_>>();
+        versions.push(Ok(version("2.0", &["1.20.4", "1.20.6"], json!([]))));
+        versions.push(Ok(version("1.0", &["1.20.1"], json!([]))));
+        let targets = ["1.20.6".into()];
+
+        let picked = pick_version(stream::iter(versions), HangarPlatform::Paper, &targets)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&*picked.name, "2.0");
+
+        let picked = pick_version(
+            stream::iter([Ok::<_, ()>(version("1.0", &["1.8"], json!([])))]),
+            HangarPlatform::Velocity,
+            &[],
+        )
+        .await
+        .unwrap();
+        assert!(picked.is_none());
+    }
+
+    /// Resolves `root` of the project "Plugin" against `published`,
+    /// the versions of each project, newest first
+    async fn resolve(
+        root: HangarVersion,
+        published: &[(&str, Vec<HangarVersion>)],
+    ) -> Result<ResolvedPlugins, DependencyError> {
+        let published = published
+            .iter()
+            .map(|(name, versions)| (name.to_lowercase(), versions.clone()))
+            .collect::<HashMap<_, _>>();
+        let targets = root.platform_versions(HangarPlatform::Paper).to_vec();
+
+        resolve_with(
+            "Plugin".into(),
+            &root,
+            &ResolveOptions::default(),
+            &targets,
+            |name| {
+                let versions = published.get(&name.to_lowercase()).cloned();
+                stream::iter(versions.unwrap_or_default().into_iter().map(Ok))
+            },
+        )
+        .await
+    }
+
+    fn names(resolved: &ResolvedPlugins) -> Vec<(&str, &str)> {
+        resolved
+            .plugins
+            .iter()
+            .map(|plugin| (&*plugin.project, &*plugin.version.name))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn resolves_transitive_dependencies() {
+        let root = version(
+            "1.0",
+            &["1.21"],
+            json!([
+                dependency("Api", true, None),
+                dependency("lib", true, None),
+                dependency("ProtocolLib", false, None),
+            ]),
+        );
+        let published = [
+            (
+                "Api",
+                vec![
+                    version("3.0", &["1.21.4"], json!([])),
+                    version("2.0", &["1.21"], json!([dependency("Lib", true, None)])),
+                ],
+            ),
+            ("Lib", vec![version("1.5", &["1.20.6", "1.21"], json!([]))]),
+        ];
+
+        let resolved = resolve(root, &published).await.unwrap();
+
+        assert_eq!(
+            names(&resolved),
+            [("lib", "1.5"), ("Api", "2.0"), ("Plugin", "1.0")]
+        );
+        assert!(resolved.external.is_empty());
+    }
+
+    #[tokio::test]
+    async fn lists_external_dependencies() {
+        let root = version(
+            "1.0",
+            &["1.21"],
+            json!([
+                dependency("LuckPerms", true, Some("https://luckperms.net/")),
+                dependency("Lib", true, None),
+                dependency("Vault", false, Some("https://example.com/vault")),
+            ]),
+        );
+        let lib = version(
+            "1.0",
+            &["1.21"],
+            json!([dependency(
+                "luckperms",
+                true,
+                Some("https://luckperms.net/")
+            )]),
+        );
+
+        let resolved = resolve(root, &[("Lib", vec![lib])]).await.unwrap();
+
+        assert_eq!(names(&resolved), [("Lib", "1.0"), ("Plugin", "1.0")]);
+        assert_eq!(
+            resolved.external,
+            [ExternalDependency {
+                name: "LuckPerms".into(),
+                url: "https://luckperms.net/".into(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn incompatible_dependency() {
+        let root = version("1.0", &["1.21"], json!([dependency("Lib", true, None)]));
+        let lib = version("1.0", &["1.20.6"], json!([]));
+
+        assert!(matches!(
+            resolve(root, &[("Lib", vec![lib])]).await,
+            Err(DependencyError::NoCompatibleVersion(name)) if &*name == "Lib"
+        ));
+    }
+
+    #[tokio::test]
+    async fn cycle() {
+        let root = version("1.0", &["1.21"], json!([dependency("A", true, None)]));
+        let a = version("1.0", &["1.21"], json!([dependency("B", true, None)]));
+        let b = version("1.0", &["1.21"], json!([dependency("A", true, None)]));
+
+        assert!(matches!(
+            resolve(root, &[("A", vec![a]), ("B", vec![b])]).await,
+            Err(DependencyError::Cycle(name)) if &*name == "A"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_dependencies() {
+        let client = Client::new();
+
+        let vquery = VersionQueryBuilder::default()
+            .platform(HangarPlatform::Paper)
+            .build();
+        let versions = get_versions(&client, "Maintenance", &vquery).await.unwrap();
+        let version = versions.result.first().unwrap();
+
+        let resolved =
+            resolve_dependencies(&client, "Maintenance", version, &ResolveOptions::default())
+                .await
+                .unwrap();
+
+        assert_eq!(resolved.plugins.last().unwrap().version.name, version.name);
+    }
+}
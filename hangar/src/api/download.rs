@@ -0,0 +1,369 @@
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use super::auth::AuthError;
+use super::error::{error_for_status, HangarError, MaybeUnauthorized};
+use crate::types::version::{HangarVersion, HangarVersionDownload};
+use crate::types::HangarPlatform;
+use log::{debug, info, warn};
+use reqwest::{Client, Url};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::fs::{self, File};
+use tokio::io::AsyncWriteExt;
+
+/// How many times a download is attempted before giving up on a hash mismatch
+const MAX_ATTEMPTS: u8 = 3;
+
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    #[error(transparent)]
+    Hangar(#[from] HangarError),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("version has no download for {0}")]
+    NoDownload(HangarPlatform),
+
+    #[error("download for {0} has neither a download nor an external url")]
+    NoUrl(HangarPlatform),
+
+    #[error("refusing to save a download as {0:?}, it isn't a plain file name")]
+    InvalidFileName(String),
+
+    #[error("hash mismatch for {file}: expected {expected}, got {actual}")]
+    HashMismatch {
+        file: Rc<str>,
+        expected: Rc<str>,
+        actual: String,
+    },
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl From<AuthError> for DownloadError {
+    fn from(err: AuthError) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl MaybeUnauthorized for DownloadError {
+    fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Hangar(err) if err.is_unauthorized())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+/// What `download_file` did to get the file on disk
+pub enum DownloadStatus {
+    /// The file was downloaded and its hash verified
+    Downloaded,
+    /// A file with a matching hash was already at the destination
+    AlreadyPresent,
+    /// The file is hosted outside of Hangar and wasn't downloaded. Hangar
+    /// knows nothing about what the URL serves, so fetching and checking it
+    /// is left to the caller
+    External(Rc<str>),
+}
+
+/// Downloads the file of `version` made for `platform` into the directory
+/// `dir`, returning where it was saved. Files hosted by Hangar keep their
+/// name. External files aren't downloaded, the returned path is where one
+/// would go, named after the last segment of its URL. Names that would land
+/// outside of `dir` are rejected. See `download_file` for details
+pub async fn download_version<P, F>(
+    client: &Client,
+    version: &HangarVersion,
+    platform: HangarPlatform,
+    dir: P,
+    progress: F,
+) -> Result<(PathBuf, DownloadStatus), DownloadError>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let download = version
+        .download_for(platform)
+        .ok_or(DownloadError::NoDownload(platform))?;
+
+    let name = match (&download.details.file_info, &download.details.external_url) {
+        (Some(file_info), _) => file_info.name.to_string(),
+        (None, Some(url)) => external_name(url).unwrap_or_else(|| format!("{}.jar", version.name)),
+        (None, None) => return Err(DownloadError::NoUrl(platform)),
+    };
+    let path = dir.as_ref().join(plain_file_name(name)?);
+
+    let status = download_file(client, download, &path, progress).await?;
+    Ok((path, status))
+}
+
+/// Streams `download` to `path`, calling `progress` with the number of bytes
+/// downloaded so far and the total size of the file after every chunk.
+///
+/// Files Hangar reports a hash for are written next to `path` and only moved
+/// there once their SHA-256 hash matches it, retrying up to three times on a
+/// mismatch. If `path` already holds a file with a matching hash, nothing is
+/// downloaded.
+///
+/// Files without a hash are hosted elsewhere and aren't downloaded, their
+/// URL is returned as `DownloadStatus::External` instead. A download that
+/// fails midway is deleted
+pub async fn download_file<P, F>(
+    client: &Client,
+    download: &HangarVersionDownload,
+    path: P,
+    mut progress: F,
+) -> Result<DownloadStatus, DownloadError>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let path = path.as_ref();
+    let details = &download.details;
+
+    let Some(file_info) = &details.file_info else {
+        let url = details
+            .external_url
+            .as_ref()
+            .or(details.download_url.as_ref())
+            .ok_or(DownloadError::NoUrl(download.platform))?;
+
+        info!("{} is hosted outside of Hangar, not downloading it", url);
+        return Ok(DownloadStatus::External(url.clone()));
+    };
+
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    let part = PathBuf::from(part);
+
+    let url = details
+        .download_url
+        .as_ref()
+        .or(details.external_url.as_ref())
+        .ok_or(DownloadError::NoUrl(download.platform))?;
+
+    if fs::try_exists(path).await? {
+        if sha256_of(path)
+            .await?
+            .eq_ignore_ascii_case(&file_info.sha_256_hash)
+        {
+            info!("{} is already downloaded", file_info.name);
+            return Ok(DownloadStatus::AlreadyPresent);
+        }
+
+        warn!("{} exists but its hash doesn't match", path.display());
+    }
+
+    let mut actual = String::new();
+    for attempt in 1..=MAX_ATTEMPTS {
+        info!(
+            "Downloading {} (attempt {}/{})",
+            file_info.name, attempt, MAX_ATTEMPTS
+        );
+        actual = fetch(
+            client,
+            url,
+            &part,
+            file_info.size_bytes as u64,
+            &mut progress,
+        )
+        .await?;
+
+        if actual.eq_ignore_ascii_case(&file_info.sha_256_hash) {
+            fs::rename(&part, path).await?;
+            debug!("Saved {} to {}", file_info.name, path.display());
+
+            return Ok(DownloadStatus::Downloaded);
+        }
+
+        warn!(
+            "Hash mismatch for {}: expected {}, got {}",
+            file_info.name, file_info.sha_256_hash, actual
+        );
+    }
+
+    fs::remove_file(&part).await?;
+    Err(DownloadError::HashMismatch {
+        file: file_info.name.clone(),
+        expected: file_info.sha_256_hash.clone(),
+        actual,
+    })
+}
+
+/// Fetches `url` into the partial file `part`, hashing it on the way.
+/// `part` is removed again if anything goes wrong, so a broken download
+/// is never mistaken for a finished one
+async fn fetch<F>(
+    client: &Client,
+    url: &str,
+    part: &Path,
+    size: u64,
+    progress: &mut F,
+) -> Result<String, DownloadError>
+where
+    F: FnMut(u64, u64),
+{
+    let fetched = async {
+        let mut resp = error_for_status(client.get(url).send().await?).await?;
+        let total = resp.content_length().unwrap_or(size);
+
+        let mut file = File::create(part).await?;
+        let mut hasher = Sha256::new();
+        let mut written = 0;
+
+        while let Some(chunk) = resp.chunk().await? {
+            file.write_all(&chunk).await?;
+            hasher.update(&chunk);
+
+            written += chunk.len() as u64;
+            progress(written, total);
+        }
+        file.flush().await?;
+
+        Ok(hex(hasher))
+    }
+    .await;
+
+    if fetched.is_err() {
+        let _ = fs::remove_file(part).await;
+    }
+
+    fetched
+}
+
+/// SHA-256 of the plugin already at `path`. Plugin jars are
+/// small, so the file is read in one go
+async fn sha256_of(path: &Path) -> Result<String, DownloadError> {
+    let mut hasher = Sha256::new();
+    hasher.update(fs::read(path).await?);
+
+    Ok(hex(hasher))
+}
+
+fn hex(hasher: Sha256) -> String {
+    format!("{:x}", hasher.finalize())
+}
+
+/// Hangar lets authors name their files, so a name is only used if it is
+/// its own file name, i.e. it has no directories or `..` in front of it
+fn plain_file_name(name: String) -> Result<String, DownloadError> {
+    if Path::new(&name).file_name() == Some(name.as_ref()) {
+        Ok(name)
+    } else {
+        Err(DownloadError::InvalidFileName(name))
+    }
+}
+
+/// The last segment of the path of `url`
+fn external_name(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let name = url.path_segments()?.next_back()?;
+
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_versions, VersionQueryBuilder};
+
+    #[test]
+    fn external_names() {
+        assert_eq!(
+            external_name("https://github.com/o/r/releases/download/1.0/Plugin.jar?raw=1"),
+            Some("Plugin.jar".to_string())
+        );
+        assert_eq!(external_name("https://example.com/"), None);
+    }
+
+    #[test]
+    fn plain_file_names() {
+        let cases = [
+            ("Maintenance-4.2.1.jar", true),
+            ("Maintenance", true),
+            ("", false),
+            ("..", false),
+            ("plugins/Maintenance.jar", false),
+            ("../../Maintenance.jar", false),
+            ("/plugins/Maintenance.jar", false),
+            ("Maintenance.jar/", false),
+        ];
+
+        for (name, plain) in cases {
+            assert_eq!(
+                plain_file_name(name.to_string()).is_ok(),
+                plain,
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn hashes_existing_plugins() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        // SHA-256 of an empty file
+        assert_eq!(
+            sha256_of(file.path()).await.unwrap(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[tokio::test]
+    async fn external_downloads_are_left_alone() {
+        let download: HangarVersionDownload = serde_json::from_str(
+            r#"{"platform": "PAPER", "externalUrl": "https://example.com/Plugin.jar"}"#,
+        )
+        .unwrap();
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(file.path(), b"plugin").await.unwrap();
+
+        let status = download_file(&Client::new(), &download, file.path(), |_, _| {})
+            .await
+            .unwrap();
+        let contents = fs::read(file.path()).await.unwrap();
+
+        assert_eq!(
+            status,
+            DownloadStatus::External(Rc::from("https://example.com/Plugin.jar"))
+        );
+        assert_eq!(contents, b"plugin");
+    }
+
+    #[tokio::test]
+    async fn test_download_version() {
+        let client = Client::new();
+
+        let vquery = VersionQueryBuilder::default()
+            .platform(HangarPlatform::Paper)
+            .build();
+        let versions = get_versions(&client, "Maintenance", &vquery).await.unwrap();
+        let version = versions.result.first().unwrap();
+
+        let dir = std::env::temp_dir().join("hangar-check-download-version");
+        let _ = fs::remove_dir_all(&dir).await;
+        fs::create_dir_all(&dir).await.unwrap();
+
+        let (path, status) =
+            download_version(&client, version, HangarPlatform::Paper, &dir, |_, _| {})
+                .await
+                .unwrap();
+        assert!(path.exists());
+        assert_eq!(status, DownloadStatus::Downloaded);
+
+        let (_, status) =
+            download_version(&client, version, HangarPlatform::Paper, &dir, |_, _| {})
+                .await
+                .unwrap();
+        assert_eq!(status, DownloadStatus::AlreadyPresent);
+
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderValue, RETRY_AFTER};
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HangarError {
+    #[error("http error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("hangar returned a token that can't be sent in a header: {0}")]
+    InvalidToken(String),
+
+    #[error("rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("hangar returned {status}: {message}")]
+    Api { status: StatusCode, message: String },
+}
+
+/// Errors that can tell whether Hangar turned a request down because of
+/// its credentials, which `AuthenticatedClient::request` retries once
+pub trait MaybeUnauthorized {
+    fn is_unauthorized(&self) -> bool;
+}
+
+impl MaybeUnauthorized for HangarError {
+    fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Unauthorized(_))
+    }
+}
+
+/// The body Hangar sends along with an error status
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+impl HangarError {
+    fn from_parts(status: StatusCode, retry_after: Option<&HeaderValue>, body: &str) -> Self {
+        let message = serde_json::from_str::<ErrorBody>(body)
+            .map(|body| body.message)
+            .unwrap_or_else(|_| body.trim().to_string());
+
+        match status {
+            StatusCode::NOT_FOUND => Self::NotFound(message),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Unauthorized(message),
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                retry_after: retry_after
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok())
+                    .map(Duration::from_secs),
+            },
+            status => Self::Api { status, message },
+        }
+    }
+}
+
+/// Passes `response` through if it was successful, and
+/// turns Hangar's error body into a `HangarError` otherwise
+pub(crate) async fn error_for_status(response: Response) -> Result<Response, HangarError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let retry_after = response.headers().get(RETRY_AFTER).cloned();
+    let body = response.text().await.unwrap_or_default();
+
+    Err(HangarError::from_parts(status, retry_after.as_ref(), &body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_messages() {
+        let body = r#"
+        {
+            "message": "The requested project could not be found",
+            "messageArgs": [],
+            "isHangarApiException": true,
+            "httpError": {
+                "statusCode": 404,
+                "statusPhrase": "Not Found"
+            }
+        }
+        "#;
+
+        assert!(matches!(
+            HangarError::from_parts(StatusCode::NOT_FOUND, None, body),
+            HangarError::NotFound(message) if message == "The requested project could not be found"
+        ));
+        assert!(matches!(
+            HangarError::from_parts(StatusCode::FORBIDDEN, None, "Forbidden"),
+            HangarError::Unauthorized(message) if message == "Forbidden"
+        ));
+        assert!(matches!(
+            HangarError::from_parts(StatusCode::BAD_REQUEST, None, body),
+            HangarError::Api {
+                status: StatusCode::BAD_REQUEST,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn rate_limits() {
+        let retry_after = HeaderValue::from_static("30");
+
+        assert!(matches!(
+            HangarError::from_parts(StatusCode::TOO_MANY_REQUESTS, Some(&retry_after), ""),
+            HangarError::RateLimited { retry_after: Some(after) } if after == Duration::from_secs(30)
+        ));
+        assert!(matches!(
+            HangarError::from_parts(StatusCode::TOO_MANY_REQUESTS, None, ""),
+            HangarError::RateLimited { retry_after: None }
+        ));
+    }
+}
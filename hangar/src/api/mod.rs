@@ -1,7 +1,32 @@
+pub mod auth;
+pub mod dependency;
+pub mod download;
+pub mod error;
+pub mod pagination;
 pub mod project;
+pub mod upload;
+pub mod user;
 pub mod version;
 
-pub use project::search_project;
-pub use version::{get_download_link, get_version, get_versions};
+#[cfg(test)]
+mod test_util;
+
+pub use auth::{authenticate, AuthError, AuthenticatedClient, HangarSession};
+pub use dependency::{
+    resolve_dependencies, DependencyError, ExternalDependency, LockedPlugin, ResolveOptions,
+    ResolvedPlugins,
+};
+pub use download::{download_file, download_version, DownloadError, DownloadStatus};
+pub use error::{HangarError, MaybeUnauthorized};
+pub use pagination::{get_versions_stream, search_project_stream};
+pub use project::{
+    get_project, get_project_members, get_project_stargazers, get_project_stats,
+    get_project_watchers, search_project,
+};
+pub use upload::{upload_version, UploadError, UploadedVersion};
+pub use user::get_user;
+pub use version::{
+    get_download_link, get_latest_release, get_latest_version, get_version, get_versions,
+};
 
 const HANGAR_ENDPOINT: &str = "https://hangar.papermc.io";
@@ -0,0 +1,181 @@
+use std::future::Future;
+
+use super::error::HangarError;
+use super::project::search_project;
+use super::version::get_versions;
+use crate::types::project::HangarProject;
+use crate::types::query::{search::SearchQuery, version::VersionQuery};
+use crate::types::version::HangarVersion;
+use crate::types::HangarProjectMeta;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use log::debug;
+use reqwest::Client;
+
+/// One page of a listing, along with the size of the whole listing
+struct Page<T> {
+    items: Vec<T>,
+    count: u64,
+}
+
+/// Streams every project matching `params`, fetching the next page once the
+/// current one has been consumed. Starts at the offset set in `params`
+/// ## Usage
+/// ```
+/// use futures_util::TryStreamExt;
+/// use hangar::{search_project_stream, SearchQueryBuilder};
+/// use reqwest::Client;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new();
+///     let query = SearchQueryBuilder::default().query("Via").build();
+///
+///     let projects = search_project_stream(&client, &query)
+///         .try_collect::<Vec<_>>()
+///         .await
+///         .unwrap();
+///
+///     assert!(!projects.is_empty());
+/// }
+/// ```
+pub fn search_project_stream<'a>(
+    client: &'a Client,
+    params: &SearchQuery,
+) -> impl Stream<Item = Result<HangarProject, HangarError>> + 'a {
+    let params = params.clone();
+
+    paginate(params.pagination.offset, move |offset| {
+        let mut params = params.clone();
+        params.pagination.offset = offset;
+
+        async move {
+            let projects = search_project(client, &params).await?;
+
+            Ok(Page {
+                items: projects.result.to_vec(),
+                count: projects.pagination.count,
+            })
+        }
+    })
+}
+
+#[expect(private_bounds)]
+/// Streams every version of `project` matching `params`, fetching the next
+/// page once the current one has been consumed. Starts at the offset set in `params`
+pub fn get_versions_stream<'a, M>(
+    client: &'a Client,
+    project: &M,
+    params: &VersionQuery,
+) -> impl Stream<Item = Result<HangarVersion, HangarError>> + 'a
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let slug = project.slug();
+    let params = params.clone();
+
+    paginate(params.pagination.offset, move |offset| {
+        let slug = slug.clone();
+        let mut params = params.clone();
+        params.pagination.offset = offset;
+
+        async move {
+            let versions = get_versions(client, &*slug, &params).await?;
+
+            Ok(Page {
+                items: versions.result,
+                count: versions.pagination.count,
+            })
+        }
+    })
+}
+
+/// Calls `fetch` with the offset of each page, starting at `offset`,
+/// until a page comes back empty or the end of the listing is reached
+fn paginate<'a, T, E, F, Fut>(offset: u64, fetch: F) -> impl Stream<Item = Result<T, E>> + 'a
+where
+    T: 'a,
+    E: 'a,
+    F: FnMut(u64) -> Fut + 'a,
+    Fut: Future<Output = Result<Page<T>, E>> + 'a,
+{
+    stream::try_unfold((offset, Some(fetch)), |(offset, fetch)| async move {
+        let Some(mut fetch) = fetch else {
+            return Ok(None);
+        };
+
+        let page = fetch(offset).await?;
+        let next = offset + page.items.len() as u64;
+        debug!("Fetched {} of {} items", next, page.count);
+
+        let more = !page.items.is_empty() && next < page.count;
+        Ok(Some((page.items, (next, more.then_some(fetch)))))
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HangarPlatform;
+    use crate::{GenericPagination, VersionQueryBuilder};
+    use futures_util::StreamExt;
+    use std::cell::RefCell;
+
+    #[tokio::test]
+    async fn walks_all_pages() {
+        let offsets = RefCell::new(Vec::new());
+
+        let items = paginate(0, |offset| {
+            offsets.borrow_mut().push(offset);
+
+            async move {
+                let items = (offset..(offset + 25).min(60)).collect::<Vec<_>>();
+                Ok::<_, ()>(Page { items, count: 60 })
+            }
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        assert_eq!(items, (0..60).collect::<Vec<_>>());
+        assert_eq!(*offsets.borrow(), [0, 25, 50]);
+    }
+
+    #[tokio::test]
+    async fn stops_on_error() {
+        let items = paginate(300, |offset| async move {
+            match offset {
+                300 => Ok(Page {
+                    items: vec![offset],
+                    count: 1000,
+                }),
+                _ => Err("offline"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(items, [Ok(300), Err("offline")]);
+    }
+
+    #[tokio::test]
+    async fn test_get_versions_stream() {
+        let client = Client::new();
+
+        let mut pagination = GenericPagination::default();
+        pagination.set_limit(5);
+        let vquery = VersionQueryBuilder::default()
+            .platform(HangarPlatform::Paper)
+            .pagination(pagination)
+            .build();
+
+        let versions = get_versions_stream(&client, "Maintenance", &vquery)
+            .take(12)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(versions.len(), 12);
+    }
+}
@@ -1,24 +1,144 @@
-use crate::types::{query::search::SearchQuery, HangarProjects};
+use super::error::{error_for_status, HangarError};
+use crate::types::{
+    project::{HangarProject, HangarProjectMembers, HangarProjectStats},
+    query::{search::SearchQuery, GenericPagination},
+    user::HangarUsers,
+    DateTime, HangarProjectMeta, HangarProjects,
+};
+use chrono::SecondsFormat;
 use reqwest::Client;
-use thiserror::Error;
-
-#[derive(Debug, Error)]
-pub enum ProjectError {
-    #[error("http error: {0}")]
-    Http(#[from] reqwest::Error),
-}
 
 pub async fn search_project(
     client: &Client,
     params: &SearchQuery,
-) -> Result<HangarProjects, ProjectError> {
-    Ok(client
+) -> Result<HangarProjects, HangarError> {
+    let response = client
         .get(format!("{}/api/v1/projects", super::HANGAR_ENDPOINT))
         .query(params)
         .send()
-        .await?
-        .json()
-        .await?)
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Gets a single project by its slug, or by its owner and slug
+/// written as `owner/slug`
+pub async fn get_project<M>(client: &Client, project: &M) -> Result<HangarProject, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let path = match project.owner() {
+        Some(owner) => format!("{}/{}", owner, project.slug()),
+        None => project.slug().to_string(),
+    };
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}",
+            super::HANGAR_ENDPOINT,
+            path
+        ))
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Lists the members of `project` along with their roles
+pub async fn get_project_members<M>(
+    client: &Client,
+    project: &M,
+    pagination: &GenericPagination,
+) -> Result<HangarProjectMembers, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/members",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .query(pagination)
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Lists the users who starred `project`
+pub async fn get_project_stargazers<M>(
+    client: &Client,
+    project: &M,
+    pagination: &GenericPagination,
+) -> Result<HangarUsers, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/stargazers",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .query(pagination)
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Lists the users watching `project`
+pub async fn get_project_watchers<M>(
+    client: &Client,
+    project: &M,
+    pagination: &GenericPagination,
+) -> Result<HangarUsers, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/watchers",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .query(pagination)
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Gets the views and downloads of `project` for every day between `from` and `to`
+pub async fn get_project_stats<M>(
+    client: &Client,
+    project: &M,
+    from: &DateTime,
+    to: &DateTime,
+) -> Result<HangarProjectStats, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/stats",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .query(&[
+            ("fromDate", from.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            ("toDate", to.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        ])
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
 }
 
 #[cfg(test)]
@@ -37,4 +157,40 @@ mod tests {
         let projects = search_project(&client, &query).await;
         assert!(projects.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_get_project() {
+        let client = Client::new();
+
+        let by_slug = get_project(&client, "Maintenance").await.unwrap();
+        let by_owner = get_project(&client, "kennytv/Maintenance").await.unwrap();
+
+        assert_eq!(by_slug.namespace.owner, by_owner.namespace.owner);
+        assert_eq!(&*by_slug.namespace.slug, "Maintenance");
+    }
+
+    #[tokio::test]
+    async fn test_get_project_members() {
+        let client = Client::new();
+
+        let members = get_project_members(&client, "Maintenance", &GenericPagination::default())
+            .await
+            .unwrap();
+
+        assert!(members
+            .result
+            .iter()
+            .any(|member| &*member.user == "kennytv"));
+    }
+
+    #[tokio::test]
+    async fn test_get_project_stats() {
+        let client = Client::new();
+
+        let from = "2024-06-01T00:00:00Z".parse().unwrap();
+        let to = "2024-06-08T00:00:00Z".parse().unwrap();
+        let stats = get_project_stats(&client, "Maintenance", &from, &to).await;
+
+        assert!(stats.is_ok_and(|stats| !stats.is_empty()));
+    }
 }
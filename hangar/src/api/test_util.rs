@@ -0,0 +1,50 @@
+use serde_json::{from_value, json};
+
+use crate::types::version::HangarVersion;
+
+/// A release of a plugin for `paper`, downloaded from Hangar
+pub(crate) fn version(
+    name: &str,
+    paper: &[&str],
+    dependencies: serde_json::Value,
+) -> HangarVersion {
+    from_value(json!({
+        "createdAt": "2024-05-17T13:48:41.703391Z",
+        "name": name,
+        "visibility": "public",
+        "description": "",
+        "author": "someone",
+        "channel": {
+            "createdAt": "2024-05-17T13:48:41.703391Z",
+            "name": "Release",
+            "description": null,
+            "color": "00E1E1",
+            "flags": []
+        },
+        "downloads": {
+            "PAPER": {
+                "fileInfo": null,
+                "externalUrl": null,
+                "downloadUrl": format!("https://hangarcdn.papermc.io/{}.jar", name)
+            }
+        },
+        "pluginDependencies": { "PAPER": dependencies },
+        "platformDependencies": { "PAPER": paper }
+    }))
+    .unwrap()
+}
+
+/// A dependency on the Paper plugin `name`, hosted
+/// elsewhere if it has an `external_url`
+pub(crate) fn dependency(
+    name: &str,
+    required: bool,
+    external_url: Option<&str>,
+) -> serde_json::Value {
+    json!({
+        "name": name,
+        "required": required,
+        "externalUrl": external_url,
+        "platform": "PAPER"
+    })
+}
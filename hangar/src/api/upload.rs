@@ -0,0 +1,111 @@
+use super::auth::AuthError;
+use super::error::{error_for_status, HangarError, MaybeUnauthorized};
+use crate::types::upload::VersionUpload;
+use crate::types::HangarProjectMeta;
+use log::info;
+use reqwest::multipart::{Form, Part};
+use reqwest::Client;
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UploadError {
+    #[error(transparent)]
+    Hangar(#[from] HangarError),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<reqwest::Error> for UploadError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl From<AuthError> for UploadError {
+    fn from(err: AuthError) -> Self {
+        Self::Hangar(err.into())
+    }
+}
+
+impl MaybeUnauthorized for UploadError {
+    fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Hangar(err) if err.is_unauthorized())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+/// What Hangar answers to a successful upload
+pub struct UploadedVersion {
+    /// The page of the new version
+    pub url: String,
+}
+
+#[expect(private_bounds)]
+/// Publishes a new version of `project`, reading the files added with
+/// `VersionUploadBuilder::file` from disk. `client` has to be authenticated
+/// with an API key allowed to create versions, see `AuthenticatedClient`
+/// ## Usage
+/// ```no_run
+/// use hangar::api::upload::upload_version;
+/// use hangar::types::HangarPlatform;
+/// use hangar::{AuthenticatedClient, VersionUploadBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let key = std::env::var("HANGAR_API_KEY").unwrap();
+///     let auth = AuthenticatedClient::new(key);
+///
+///     let upload = &VersionUploadBuilder::default()
+///         .version("1.2.0")
+///         .description("Bug fixes")
+///         .platform_versions(HangarPlatform::Paper, &["1.21"])
+///         .file(&[HangarPlatform::Paper], "build/libs/MyPlugin-1.2.0.jar")
+///         .build();
+///
+///     let uploaded = auth
+///         .request(|client| async move { upload_version(&client, "MyPlugin", upload).await })
+///         .await
+///         .unwrap();
+///     println!("Published at {}", uploaded.url);
+/// }
+/// ```
+pub async fn upload_version<M>(
+    client: &Client,
+    project: &M,
+    upload: &VersionUpload,
+) -> Result<UploadedVersion, UploadError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    info!("Uploading version {} of {}", upload.version, project.slug());
+
+    let metadata = Part::text(serde_json::to_string(upload)?).mime_str("application/json")?;
+    let mut form = Form::new().part("versionUpload", metadata);
+
+    for path in &upload.paths {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file = Part::bytes(tokio::fs::read(path).await?).file_name(name);
+
+        form = form.part("files", file);
+    }
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/projects/{}/upload",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .multipart(form)
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
@@ -0,0 +1,26 @@
+use super::error::{error_for_status, HangarError};
+use crate::types::user::HangarUser;
+use reqwest::Client;
+
+/// Gets a single user or organization by name
+pub async fn get_user(client: &Client, name: &str) -> Result<HangarUser, HangarError> {
+    let response = client
+        .get(format!("{}/api/v1/users/{}", super::HANGAR_ENDPOINT, name))
+        .send()
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_user() {
+        let client = Client::new();
+
+        let user = get_user(&client, "kennytv").await;
+        assert!(user.is_ok_and(|user| &*user.name == "kennytv"));
+    }
+}
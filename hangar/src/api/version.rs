@@ -1,54 +1,103 @@
 use std::fmt::Display;
+use std::rc::Rc;
 
+use super::error::{error_for_status, HangarError};
 use crate::types::{
-    project::HangarProject, query::version::VersionQuery, version::HangarVersion, HangarPlatform,
+    query::version::VersionQuery, version::HangarVersion, HangarPlatform, HangarProjectMeta,
     HangarVersions,
 };
 use reqwest::Client;
-use thiserror::Error;
 
-#[derive(Debug, Error)]
-pub enum VersionError {
-    #[error("http error: {0}")]
-    Http(#[from] reqwest::Error),
-}
-
-pub async fn get_versions(
+#[expect(private_bounds)]
+/// Lists the versions of `project`, given as a `HangarProject` or its slug
+pub async fn get_versions<M>(
     client: &Client,
-    project: &HangarProject,
+    project: &M,
     params: &VersionQuery,
-) -> Result<HangarVersions, VersionError> {
-    Ok(client
+) -> Result<HangarVersions, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
         .get(format!(
             "{}/api/v1/projects/{}/versions",
             super::HANGAR_ENDPOINT,
-            project.namespace.slug
+            project.slug()
         ))
         .query(params)
         .send()
-        .await?
-        .json()
-        .await?)
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
 }
 
-pub async fn get_version(
+#[expect(private_bounds)]
+/// Gets the version named `version` of `project`, given as a `HangarProject` or its slug
+pub async fn get_version<M>(
     client: &Client,
-    project: &HangarProject,
+    project: &M,
     version: String,
     params: &VersionQuery,
-) -> Result<HangarVersion, VersionError> {
-    Ok(client
+) -> Result<HangarVersion, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
         .get(format!(
             "{}/api/v1/projects/{}/versions/{}",
             super::HANGAR_ENDPOINT,
-            project.namespace.slug,
+            project.slug(),
             version
         ))
         .query(params)
         .send()
-        .await?
-        .json()
-        .await?)
+        .await?;
+
+    Ok(error_for_status(response).await?.json().await?)
+}
+
+#[expect(private_bounds)]
+/// Gets the name of the newest version of `project` in a channel
+/// that isn't marked as unstable. See `get_version` for the details
+pub async fn get_latest_release<M>(client: &Client, project: &M) -> Result<Rc<str>, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/latestrelease",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .send()
+        .await?;
+    let name = error_for_status(response).await?.text().await?;
+
+    Ok(name.into())
+}
+
+#[expect(private_bounds)]
+/// Gets the name of the newest version of `project` in `channel`
+pub async fn get_latest_version<M>(
+    client: &Client,
+    project: &M,
+    channel: &str,
+) -> Result<Rc<str>, HangarError>
+where
+    M: HangarProjectMeta + ?Sized,
+{
+    let response = client
+        .get(format!(
+            "{}/api/v1/projects/{}/latest",
+            super::HANGAR_ENDPOINT,
+            project.slug()
+        ))
+        .query(&[("channel", channel)])
+        .send()
+        .await?;
+    let name = error_for_status(response).await?.text().await?;
+
+    Ok(name.into())
 }
 
 pub fn get_download_link<T: Display>(slug: T, name: T, platform: HangarPlatform) -> String {
@@ -84,4 +133,31 @@ mod tests {
 
         assert!(versions.is_ok_and(|v| !v.result.is_empty()))
     }
+
+    #[tokio::test]
+    async fn test_get_versions_by_slug() {
+        let client = Client::new();
+
+        let vquery = VersionQueryBuilder::default()
+            .platform(HangarPlatform::Paper)
+            .build();
+        let versions = get_versions(&client, "Maintenance", &vquery).await.unwrap();
+        let latest = versions.result.first().unwrap();
+
+        let version = get_version(&client, "Maintenance", latest.name.to_string(), &vquery).await;
+
+        assert!(version.is_ok_and(|v| v.name == latest.name))
+    }
+
+    #[tokio::test]
+    async fn test_get_latest_release() {
+        let client = Client::new();
+
+        let release = get_latest_release(&client, "Maintenance").await.unwrap();
+        let latest = get_latest_version(&client, "Maintenance", "Release")
+            .await
+            .unwrap();
+
+        assert_eq!(release, latest);
+    }
 }
@@ -4,8 +4,16 @@ pub mod api;
 pub mod types;
 
 #[cfg(feature = "api")]
-pub use api::{get_download_link, get_version, get_versions, search_project};
+pub use api::{
+    authenticate, download_file, download_version, get_download_link, get_latest_release,
+    get_latest_version, get_project, get_project_members, get_project_stargazers,
+    get_project_stats, get_project_watchers, get_user, get_version, get_versions,
+    get_versions_stream, resolve_dependencies, search_project, search_project_stream,
+    upload_version, AuthenticatedClient, DownloadStatus, HangarError, ResolveOptions,
+};
 #[cfg(feature = "types")]
 pub use types::query::{GenericPagination, SearchQueryBuilder, VersionQueryBuilder};
 #[cfg(feature = "types")]
+pub use types::upload::VersionUploadBuilder;
+#[cfg(feature = "types")]
 pub use types::{HangarProjects, HangarVersions, HangarVisibility};
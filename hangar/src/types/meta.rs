@@ -0,0 +1,79 @@
+use std::rc::Rc;
+
+/// Anything that identifies a Hangar project: a `HangarProject`,
+/// its namespace, or a slug, optionally prefixed by its owner
+/// as in `owner/slug`
+pub(crate) trait HangarProjectMeta {
+    fn slug(&self) -> Rc<str>;
+
+    fn owner(&self) -> Option<Rc<str>> {
+        None
+    }
+}
+
+impl<M: HangarProjectMeta + ?Sized> HangarProjectMeta for &M {
+    fn slug(&self) -> Rc<str> {
+        (**self).slug()
+    }
+
+    fn owner(&self) -> Option<Rc<str>> {
+        (**self).owner()
+    }
+}
+
+impl HangarProjectMeta for str {
+    fn slug(&self) -> Rc<str> {
+        match self.split_once('/') {
+            Some((_, slug)) => slug.into(),
+            None => self.into(),
+        }
+    }
+
+    fn owner(&self) -> Option<Rc<str>> {
+        self.split_once('/').map(|(owner, _)| owner.into())
+    }
+}
+
+impl HangarProjectMeta for String {
+    fn slug(&self) -> Rc<str> {
+        self.as_str().slug()
+    }
+
+    fn owner(&self) -> Option<Rc<str>> {
+        self.as_str().owner()
+    }
+}
+
+impl HangarProjectMeta for super::project::HangarProjectNamespace {
+    fn slug(&self) -> Rc<str> {
+        self.slug.clone()
+    }
+
+    fn owner(&self) -> Option<Rc<str>> {
+        Some(self.owner.clone())
+    }
+}
+
+impl HangarProjectMeta for super::project::HangarProject {
+    fn slug(&self) -> Rc<str> {
+        self.namespace.slug()
+    }
+
+    fn owner(&self) -> Option<Rc<str>> {
+        self.namespace.owner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_meta_from_str() {
+        assert_eq!(&*"Maintenance".slug(), "Maintenance");
+        assert_eq!("Maintenance".owner(), None);
+
+        assert_eq!(&*"kennytv/Maintenance".slug(), "Maintenance");
+        assert_eq!("kennytv/Maintenance".owner().as_deref(), Some("kennytv"));
+    }
+}
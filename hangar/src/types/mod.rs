@@ -5,14 +5,29 @@ use serde::{Deserialize, Serialize};
 
 pub mod project;
 pub mod query;
+pub mod upload;
+pub mod user;
 pub mod version;
 
+#[cfg(feature = "api")]
+mod meta;
+#[cfg(feature = "api")]
+pub(crate) use meta::HangarProjectMeta;
+
 pub use project::HangarProjects;
 pub use version::HangarVersions;
 
-type DateTime = chrono::DateTime<chrono::Utc>;
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+#[derive(Debug, Deserialize, Clone)]
+/// Where a page sits in a listing, shared by every paginated endpoint
+pub struct HangarPagination {
+    pub limit: u32,
+    pub offset: u64,
+    pub count: u64,
+}
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum HangarVisibility {
     Public,
@@ -20,15 +35,24 @@ pub enum HangarVisibility {
     NeedsChanges,
     NeedsApproval,
     SoftDelete,
+    /// A visibility this crate doesn't know about yet
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize, Hash, PartialEq, Eq)]
+#[derive(
+    Debug, Default, Deserialize, Serialize, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy,
+)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum HangarPlatform {
     #[default]
     Paper,
     Waterfall,
     Velocity,
+    /// A platform this crate doesn't know about yet.
+    /// Can't be used to query Hangar
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 impl Display for HangarPlatform {
@@ -40,17 +64,18 @@ impl Display for HangarPlatform {
                 Self::Paper => "PAPER",
                 Self::Waterfall => "WATERFALL",
                 Self::Velocity => "VELOCITY",
+                Self::Unknown => "UNKNOWN",
             }
         )
     }
 }
 
 bitflags! {
-    #[derive(Debug, Serialize, Default)]
+    #[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq)]
     pub struct HangarTags: u8 {
         const ADDON          = 1;
-        const LIBRARY        = 2;
-        const SUPPORTS_FOLIA = 3;
+        const LIBRARY        = 1 << 1;
+        const SUPPORTS_FOLIA = 1 << 2;
     }
 }
 
@@ -60,22 +85,29 @@ impl<'de> Deserialize<'de> for HangarTags {
         D: serde::Deserializer<'de>,
     {
         let tags: Vec<String> = Vec::deserialize(deserializer)?;
-        let mut flags = Self::empty();
-
-        for tag in tags {
-            match tag.as_str() {
-                "ADDON" => flags |= Self::ADDON,
-                "LIBRARY" => flags |= Self::LIBRARY,
-                "SUPPORTS_FOLIA" => flags |= Self::SUPPORTS_FOLIA,
-                other => {
-                    return Err(serde::de::Error::unknown_variant(
-                        other,
-                        &["ADDON", "LIBRARY", "SUPPORTS_FOLIA"],
-                    ))
-                }
-            }
-        }
 
-        Ok(flags)
+        // Tags added by Hangar later are left out rather than
+        // failing the whole project, and every listing with it
+        Ok(tags.iter().filter_map(|tag| Self::from_name(tag)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_values() {
+        let platform: HangarPlatform = serde_json::from_str(r#""FOLIA""#).unwrap();
+        assert_eq!(platform, HangarPlatform::Unknown);
+        assert!(serde_json::to_string(&platform).is_err());
+
+        let visibility: HangarVisibility = serde_json::from_str(r#""archived""#).unwrap();
+        assert_eq!(visibility, HangarVisibility::Unknown);
+
+        let tags: HangarTags =
+            serde_json::from_str(r#"["ADDON", "SUPPORTS_FOLIA", "SOMETHING_NEW"]"#).unwrap();
+        assert_eq!(tags, HangarTags::ADDON | HangarTags::SUPPORTS_FOLIA);
+        assert!(!tags.contains(HangarTags::LIBRARY));
     }
 }
@@ -1,23 +1,18 @@
-use super::{DateTime, HangarTags, HangarVisibility};
+use super::{DateTime, HangarPagination, HangarTags, HangarVisibility};
+use chrono::NaiveDate;
 use serde::Deserialize;
-use std::{fmt::Debug, rc::Rc};
+use std::{collections::BTreeMap, fmt::Debug, rc::Rc};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProjects {
     pub pagination: HangarProjectsPagination,
     pub result: Rc<[HangarProject]>,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct HangarProjectsPagination {
-    pub limit: u8,
-    pub offset: u8,
-    pub count: u16,
-}
+pub type HangarProjectsPagination = HangarPagination;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProject {
     pub created_at: DateTime,
@@ -31,7 +26,7 @@ pub struct HangarProject {
     pub settings: HangarProjectSettings,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProjectSettings {
     pub links: Option<Rc<[HangarProjectLinks]>>,
@@ -40,21 +35,21 @@ pub struct HangarProjectSettings {
     pub keywords: Vec<Rc<str>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProjectNamespace {
     pub owner: Rc<str>,
     pub slug: Rc<str>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProjectLinks {
     #[serde(deserialize_with = "deserialize_links")]
     pub links: Rc<[HangarProjectLink]>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct HangarProjectLink {
     pub id: u8,
     pub name: Rc<str>,
@@ -62,7 +57,7 @@ pub struct HangarProjectLink {
     pub url: Rc<str>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HangarProjectCategory {
     AdminTools,
@@ -76,9 +71,12 @@ pub enum HangarProjectCategory {
     WorldManagement,
     Misc,
     Undefined,
+    /// A category this crate doesn't know about yet
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarProjectLicense {
     pub name: Option<Rc<str>>,
@@ -88,6 +86,44 @@ pub struct HangarProjectLicense {
     pub license_type: Rc<str>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HangarProjectMembers {
+    pub pagination: HangarPagination,
+    pub result: Vec<HangarProjectMember>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// A user taking part in a project
+pub struct HangarProjectMember {
+    /// The name of the user
+    pub user: Rc<str>,
+    pub user_id: u64,
+    pub roles: Vec<HangarProjectRole>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HangarProjectRole {
+    /// The name of the role, as in `Owner` or `Developer`
+    pub title: Rc<str>,
+    pub color: Rc<str>,
+    /// Lower ranks have more permissions
+    pub rank: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+/// The views and downloads of a project over a single day
+pub struct HangarDayStats {
+    pub views: u64,
+    pub downloads: u64,
+}
+
+/// Daily stats of a project, keyed by day
+pub type HangarProjectStats = BTreeMap<NaiveDate, HangarDayStats>;
+
 fn deserialize_links<'de, D>(deserializer: D) -> Result<Rc<[HangarProjectLink]>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -2267,4 +2303,63 @@ mod tests {
 
         let _projects: HangarProjects = projects.unwrap();
     }
+
+    #[test]
+    fn unknown_category() {
+        let category: HangarProjectCategory = from_str(r#""world_management""#).unwrap();
+        assert_eq!(category, HangarProjectCategory::WorldManagement);
+
+        let category: HangarProjectCategory = from_str(r#""cosmetics""#).unwrap();
+        assert_eq!(category, HangarProjectCategory::Unknown);
+    }
+
+    #[test]
+    fn members() {
+        let raw = r##"
+{
+    "pagination": {
+        "limit": 25,
+        "offset": 0,
+        "count": 1
+    },
+    "result": [
+        {
+            "user": "kennytv",
+            "userId": 3,
+            "roles": [
+                {
+                    "roleId": 20,
+                    "value": "PROJECT_OWNER",
+                    "category": "project",
+                    "permission": "...",
+                    "title": "Owner",
+                    "color": "#2cb07c",
+                    "assignable": false,
+                    "rank": null
+                }
+            ]
+        }
+    ]
+}
+        "##;
+
+        let members: HangarProjectMembers = from_str(raw).unwrap();
+        assert_eq!(&*members.result[0].roles[0].title, "Owner");
+    }
+
+    #[test]
+    fn stats() {
+        let raw = r#"
+{
+    "2024-06-01": { "views": 120, "downloads": 40 },
+    "2024-06-02": { "views": 98, "downloads": 31 }
+}
+        "#;
+
+        let stats: HangarProjectStats = from_str(raw).unwrap();
+        let day = NaiveDate::from_ymd_opt(2024, 6, 2).unwrap();
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[&day].downloads, 31);
+    }
 }
@@ -6,10 +6,10 @@ pub use version::VersionQueryBuilder;
 
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct GenericPagination {
-    pub(crate) limit: u8,
-    pub(crate) offset: u8,
+    pub(crate) limit: u32,
+    pub(crate) offset: u64,
 }
 
 impl Default for GenericPagination {
@@ -22,11 +22,11 @@ impl Default for GenericPagination {
 }
 
 impl GenericPagination {
-    pub fn set_limit(&mut self, limit: u8) {
+    pub fn set_limit(&mut self, limit: u32) {
         self.limit = limit;
     }
 
-    pub fn set_offset(&mut self, offset: u8) {
+    pub fn set_offset(&mut self, offset: u64) {
         self.offset = offset;
     }
 }
@@ -4,7 +4,7 @@ use serde::Serialize;
 
 use std::rc::Rc;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchQuery {
     pub(crate) prioritize_exact_match: bool,
@@ -26,7 +26,7 @@ pub struct SearchQuery {
     pub(crate) tag: HangarTags,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum SortBy {
     Views,
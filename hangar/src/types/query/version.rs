@@ -4,7 +4,7 @@ use serde::Serialize;
 
 use std::rc::Rc;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct VersionQuery {
     #[serde(flatten)]
@@ -0,0 +1,188 @@
+use super::version::HPPluginDependencyDetails;
+use super::HangarPlatform;
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The metadata of a version to upload, sent along
+/// with its files as the `versionUpload` part
+pub struct VersionUpload {
+    pub(crate) version: Rc<str>,
+    pub(crate) channel: Rc<str>,
+    pub(crate) description: Rc<str>,
+    pub(crate) plugin_dependencies: BTreeMap<HangarPlatform, Vec<HPPluginDependencyDetails>>,
+    pub(crate) platform_dependencies: BTreeMap<HangarPlatform, Vec<Rc<str>>>,
+    pub(crate) files: Vec<UploadFile>,
+
+    /// The files to send, in the order of the entries
+    /// in `files` without an external URL
+    #[cfg(feature = "api")]
+    #[serde(skip)]
+    pub(crate) paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UploadFile {
+    pub(crate) platforms: Vec<HangarPlatform>,
+    pub(crate) external_url: Option<Rc<str>>,
+}
+
+#[derive(Debug, Default)]
+pub struct VersionUploadBuilder {
+    version: Option<Rc<str>>,
+    channel: Option<Rc<str>>,
+    description: Option<Rc<str>>,
+    plugin_dependencies: BTreeMap<HangarPlatform, Vec<HPPluginDependencyDetails>>,
+    platform_dependencies: BTreeMap<HangarPlatform, Vec<Rc<str>>>,
+    files: Vec<UploadFile>,
+    #[cfg(feature = "api")]
+    paths: Vec<PathBuf>,
+}
+
+impl VersionUploadBuilder {
+    pub fn version<T: ToString>(mut self, version: T) -> Self {
+        self.version = Some(Rc::from(version.to_string().into_boxed_str()));
+
+        self
+    }
+
+    /// The channel to publish to, `Release` if not set
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(Rc::from(channel.to_string().into_boxed_str()));
+
+        self
+    }
+
+    /// The changelog of the version, in Markdown
+    pub fn description<T: ToString>(mut self, description: T) -> Self {
+        self.description = Some(Rc::from(description.to_string().into_boxed_str()));
+
+        self
+    }
+
+    /// Adds a plugin the version depends on when running on `platform`. Leave
+    /// `external_url` empty for plugins hosted on Hangar, `name` being their slug
+    pub fn plugin_dependency<T: ToString>(
+        mut self,
+        platform: HangarPlatform,
+        name: T,
+        required: bool,
+        external_url: Option<&str>,
+    ) -> Self {
+        self.plugin_dependencies
+            .entry(platform)
+            .or_default()
+            .push(HPPluginDependencyDetails {
+                name: Rc::from(name.to_string().into_boxed_str()),
+                required,
+                external_url: external_url.map(Rc::from),
+                platform,
+            });
+
+        self
+    }
+
+    /// Sets the versions of `platform` the version runs on
+    pub fn platform_versions<T: ToString>(
+        mut self,
+        platform: HangarPlatform,
+        versions: &[T],
+    ) -> Self {
+        self.platform_dependencies.insert(
+            platform,
+            versions
+                .iter()
+                .map(|version| Rc::from(version.to_string().into_boxed_str()))
+                .collect(),
+        );
+
+        self
+    }
+
+    /// Adds the file at `path`, to be uploaded for `platforms`
+    pub fn file<P: Into<PathBuf>>(mut self, platforms: &[HangarPlatform], path: P) -> Self {
+        self.files.push(UploadFile {
+            platforms: platforms.to_vec(),
+            external_url: None,
+        });
+        #[cfg(feature = "api")]
+        self.paths.push(path.into());
+        #[cfg(not(feature = "api"))]
+        let _ = path;
+
+        self
+    }
+
+    /// Adds a file hosted outside of Hangar for `platforms`
+    pub fn external_file(mut self, platforms: &[HangarPlatform], url: &str) -> Self {
+        self.files.push(UploadFile {
+            platforms: platforms.to_vec(),
+            external_url: Some(Rc::from(url)),
+        });
+
+        self
+    }
+
+    pub fn build(self) -> VersionUpload {
+        VersionUpload {
+            version: self.version.unwrap_or_default(),
+            channel: self.channel.unwrap_or_else(|| Rc::from("Release")),
+            description: self.description.unwrap_or_default(),
+            plugin_dependencies: self.plugin_dependencies,
+            platform_dependencies: self.platform_dependencies,
+            files: self.files,
+            #[cfg(feature = "api")]
+            paths: self.paths,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn upload_serialization() {
+        let upload = VersionUploadBuilder::default()
+            .version("1.2.0")
+            .description("Fixes")
+            .platform_versions(HangarPlatform::Paper, &["1.20.6", "1.21"])
+            .plugin_dependency(HangarPlatform::Paper, "ProtocolLib", false, None)
+            .file(&[HangarPlatform::Paper], "build/Plugin.jar")
+            .external_file(
+                &[HangarPlatform::Velocity],
+                "https://example.com/Plugin.jar",
+            )
+            .build();
+
+        assert_eq!(
+            to_value(&upload).unwrap(),
+            json!({
+                "version": "1.2.0",
+                "channel": "Release",
+                "description": "Fixes",
+                "pluginDependencies": {
+                    "PAPER": [{
+                        "name": "ProtocolLib",
+                        "required": false,
+                        "externalUrl": null,
+                        "platform": "PAPER"
+                    }]
+                },
+                "platformDependencies": { "PAPER": ["1.20.6", "1.21"] },
+                "files": [
+                    { "platforms": ["PAPER"], "externalUrl": null },
+                    { "platforms": ["VELOCITY"], "externalUrl": "https://example.com/Plugin.jar" }
+                ]
+            })
+        );
+        #[cfg(feature = "api")]
+        assert_eq!(upload.paths, [PathBuf::from("build/Plugin.jar")]);
+    }
+}
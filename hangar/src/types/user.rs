@@ -0,0 +1,117 @@
+use super::{DateTime, HangarPagination};
+use serde::Deserialize;
+use std::rc::Rc;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HangarUsers {
+    pub pagination: HangarPagination,
+    pub result: Vec<HangarUser>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HangarUser {
+    pub created_at: DateTime,
+    pub name: Rc<str>,
+    pub id: u64,
+    pub tagline: Option<Rc<str>>,
+    pub avatar_url: Rc<str>,
+    /// How many projects the user owns
+    pub project_count: u64,
+    pub locked: bool,
+    pub is_organization: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn many_users() {
+        let raw = r#"
+{
+    "pagination": {
+        "limit": 25,
+        "offset": 0,
+        "count": 1
+    },
+    "result": [
+        {
+            "createdAt": "2022-12-22T13:46:07.212354Z",
+            "name": "kennytv",
+            "id": 3,
+            "tagline": null,
+            "roles": [3],
+            "projectCount": 4,
+            "locked": false,
+            "nameHistory": [],
+            "avatarUrl": "https://hangarcdn.papermc.io/avatars/user/3.webp?v=1",
+            "isOrganization": false,
+            "socials": {}
+        }
+    ]
+}
+        "#;
+
+        let users: HangarUsers = from_str(raw).unwrap();
+        assert_eq!(&*users.result[0].name, "kennytv");
+        assert!(users.result[0].tagline.is_none());
+    }
+
+    #[test]
+    fn organization() {
+        let raw = r#"
+{
+    "createdAt": "2023-01-12T18:01:25.612874Z",
+    "name": "PaperMC",
+    "id": 1842,
+    "tagline": "High performance Minecraft server software",
+    "roles": [],
+    "projectCount": 0,
+    "locked": false,
+    "nameHistory": [
+        {
+            "oldName": "Paper",
+            "newName": "PaperMC",
+            "date": "2023-01-12T18:05:00.000000Z"
+        }
+    ],
+    "avatarUrl": "https://hangarcdn.papermc.io/avatars/user/1842.webp?v=2",
+    "isOrganization": true,
+    "socials": {
+        "github": "PaperMC"
+    }
+}
+        "#;
+
+        let user: HangarUser = from_str(raw).unwrap();
+        assert!(user.is_organization);
+        assert_eq!(user.project_count, 0);
+        assert_eq!(
+            user.tagline.as_deref(),
+            Some("High performance Minecraft server software")
+        );
+    }
+
+    #[test]
+    fn locked_user() {
+        let raw = r#"
+{
+    "createdAt": "2022-12-22T13:46:07.212354Z",
+    "name": "someone",
+    "id": 9001,
+    "roles": [],
+    "projectCount": 0,
+    "locked": true,
+    "avatarUrl": "https://hangarcdn.papermc.io/avatars/user/9001.webp?v=1",
+    "isOrganization": false
+}
+        "#;
+
+        let user: HangarUser = from_str(raw).unwrap();
+        assert!(user.locked);
+        assert!(user.tagline.is_none());
+    }
+}
@@ -1,6 +1,7 @@
-use super::{DateTime, HangarPlatform, HangarVisibility};
-use details::*;
-use serde::Deserialize;
+use super::{DateTime, HangarPagination, HangarPlatform, HangarVisibility};
+use bitflags::bitflags;
+pub use details::*;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 mod details;
@@ -12,14 +13,9 @@ pub struct HangarVersions {
     pub result: Vec<HangarVersion>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct HangarVersionsPagination {
-    pub limit: u8,
-    pub offset: u8,
-    pub count: u16,
-}
+pub type HangarVersionsPagination = HangarPagination;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarVersion {
     pub created_at: DateTime,
@@ -27,6 +23,7 @@ pub struct HangarVersion {
     pub visibility: HangarVisibility,
     pub description: Rc<str>,
     pub author: Rc<str>,
+    pub channel: HangarChannel,
     #[serde(deserialize_with = "traits::deserialize_kv")]
     pub downloads: Vec<HangarVersionDownload>,
     #[serde(deserialize_with = "traits::deserialize_kv")]
@@ -35,7 +32,94 @@ pub struct HangarVersion {
     pub platform_dependencies: Vec<HangarVersionPlatformDependencies>,
 }
 
-#[derive(Debug, Deserialize)]
+impl HangarVersion {
+    /// The download for `platform`, if this version supports it
+    pub fn download_for(&self, platform: HangarPlatform) -> Option<&HangarVersionDownload> {
+        self.downloads
+            .iter()
+            .find(|download| download.platform == platform)
+    }
+
+    /// The plugins this version depends on when running on `platform`
+    pub fn plugin_dependencies_for(
+        &self,
+        platform: HangarPlatform,
+    ) -> &[HPPluginDependencyDetails] {
+        self.plugin_dependencies
+            .iter()
+            .find(|dependencies| dependencies.platform == platform)
+            .map(|dependencies| &*dependencies.details)
+            .unwrap_or_default()
+    }
+
+    /// The versions of `platform` this version runs on
+    pub fn platform_versions(&self, platform: HangarPlatform) -> &[Rc<str>] {
+        self.platform_dependencies
+            .iter()
+            .find(|dependencies| dependencies.platform == platform)
+            .map(|dependencies| &*dependencies.version)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// The channel a version was published to, as in `Release` or `Snapshot`
+pub struct HangarChannel {
+    pub created_at: DateTime,
+    pub name: Rc<str>,
+    pub description: Option<Rc<str>>,
+    /// The color of the channel, as a hex string without a leading `#`
+    pub color: Rc<str>,
+    pub flags: HangarChannelFlags,
+}
+
+impl HangarChannel {
+    /// Whether versions in this channel are meant for testing only
+    pub fn is_unstable(&self) -> bool {
+        self.flags.contains(HangarChannelFlags::UNSTABLE)
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct HangarChannelFlags: u8 {
+        const FROZEN              = 1;
+        const UNSTABLE            = 1 << 1;
+        const PINNED              = 1 << 2;
+        const SENDS_NOTIFICATIONS = 1 << 3;
+        const HIDE_BY_DEFAULT     = 1 << 4;
+        const SKIP_REVIEW_QUEUE   = 1 << 5;
+    }
+}
+
+/// Written as a list of flag names, the way Hangar sends them
+impl Serialize for HangarChannelFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter_names().map(|(name, _)| name))
+    }
+}
+
+impl<'de> Deserialize<'de> for HangarChannelFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let names: Vec<String> = Vec::deserialize(deserializer)?;
+
+        // Every version has a channel, so failing on a flag added
+        // by Hangar later would make every version unreadable
+        Ok(names
+            .iter()
+            .filter_map(|name| Self::from_name(name))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarVersionDownload {
     pub platform: HangarPlatform,
@@ -56,28 +140,29 @@ impl traits::KeyValueType for HangarVersionDownload {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
+/// The plugins a version depends on when running on `platform`
 pub struct HangarVersionPluginDependencies {
-    pub name: Rc<str>,
+    pub platform: HangarPlatform,
 
     #[serde(flatten)]
     pub details: Rc<[HPPluginDependencyDetails]>,
 }
 
 impl traits::KeyValueType for HangarVersionPluginDependencies {
-    type Key = Rc<str>;
+    type Key = HangarPlatform;
     type Value = Rc<[HPPluginDependencyDetails]>;
 
     fn init(key: Self::Key, value: Self::Value) -> Self {
         Self {
-            name: key,
+            platform: key,
             details: value,
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarVersionPlatformDependencies {
     pub platform: HangarPlatform,
@@ -101,6 +186,22 @@ mod tests {
     use super::*;
     use serde_json::from_str;
 
+    #[test]
+    fn unknown_channel_flags() {
+        let flags: HangarChannelFlags = from_str(r#"["UNSTABLE", "SOMETHING_NEW"]"#).unwrap();
+
+        assert_eq!(flags, HangarChannelFlags::UNSTABLE);
+    }
+
+    #[test]
+    fn channel_flags_round_trip() {
+        let flags = HangarChannelFlags::PINNED | HangarChannelFlags::FROZEN;
+        let raw = serde_json::to_string(&flags).unwrap();
+
+        assert_eq!(raw, r#"["FROZEN","PINNED"]"#);
+        assert_eq!(from_str::<HangarChannelFlags>(&raw).unwrap(), flags);
+    }
+
     #[test]
     fn one_version() {
         let raw = r#"
@@ -243,7 +344,16 @@ mod tests {
         let version = from_str(raw);
 
         assert!(version.is_ok());
-        let _version: HangarVersion = version.unwrap();
+        let version: HangarVersion = version.unwrap();
+
+        assert_eq!(&*version.channel.name, "Release");
+        assert_eq!(
+            version.channel.flags,
+            HangarChannelFlags::SENDS_NOTIFICATIONS
+                | HangarChannelFlags::PINNED
+                | HangarChannelFlags::FROZEN
+        );
+        assert!(!version.channel.is_unstable());
     }
 
     #[test]
@@ -1,25 +1,29 @@
 use super::HangarPlatform;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use std::rc::Rc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HPDownloadDetails {
-    pub file_info: HangarVersionDownloadFile,
+    /// Missing for files hosted outside of Hangar
+    pub file_info: Option<HangarVersionDownloadFile>,
     pub external_url: Option<Rc<str>>,
     pub download_url: Option<Rc<str>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HPPluginDependencyDetails {
+    /// The name of the plugin, which is also its
+    /// slug if the plugin is hosted on Hangar
+    pub name: Rc<str>,
     pub required: bool,
     pub external_url: Option<Rc<str>>,
     pub platform: HangarPlatform,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HangarVersionDownloadFile {
     pub name: Rc<str>,
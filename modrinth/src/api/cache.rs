@@ -0,0 +1,550 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::APIError;
+use log::{debug, info, warn};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CACHE_CONTROL, ETAG, IF_NONE_MATCH};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::fs;
+use tokio::sync::OnceCell;
+
+/// How many responses are kept on disk unless set with `Cache::disk_capacity`
+const DEFAULT_DISK_CAPACITY: usize = 4096;
+
+/// What the names of cache files start with, so that only
+/// those are ever pruned from the directory they're kept in
+const FILE_PREFIX: &str = "modrinth-";
+
+/// Caches responses of Modrinth's read-only endpoints, such as the ones
+/// `search_project`, `get_project`, `get_versions` and `resolve_dependencies`
+/// rely on. Requests only go through a cache when sent with a `CachedClient`.
+/// Requests made with a token, which answer with private data, are never cached.
+///
+/// Responses are kept in memory, evicting the least recently used
+/// ones, and optionally in a directory so they survive restarts.
+/// Cached responses are reused for as long as Modrinth's `Cache-Control`
+/// header allows, then revalidated with their `ETag`. When Modrinth
+/// can't be reached, stale responses are used instead.
+pub struct Cache {
+    capacity: usize,
+    dir: Option<PathBuf>,
+    disk_capacity: usize,
+    memory: Mutex<Lru>,
+    /// The cache files in `dir`, by when they were written. Filled
+    /// by listing `dir` the first time a response is stored
+    disk: OnceCell<Mutex<Recency<PathBuf>>>,
+}
+
+impl Cache {
+    /// Creates an in-memory cache holding up to `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            dir: None,
+            disk_capacity: DEFAULT_DISK_CAPACITY,
+            memory: Mutex::new(Lru::default()),
+            disk: OnceCell::new(),
+        }
+    }
+
+    /// Also stores responses in `dir`, one file per URL. Other
+    /// files in `dir` are left alone
+    pub fn with_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// How many responses are kept in the directory set with `with_dir`,
+    /// deleting the ones written longest ago first. Defaults to 4096
+    pub fn disk_capacity(mut self, capacity: usize) -> Self {
+        self.disk_capacity = capacity;
+        self
+    }
+
+    /// Sends `request`, or answers it from the cache, and returns the response body
+    async fn fetch(&self, client: &Client, request: RequestBuilder) -> Result<String, APIError> {
+        let mut request = request.build()?;
+        if request.headers().contains_key(AUTHORIZATION) {
+            return Ok(client
+                .execute(request)
+                .await?
+                .error_for_status()?
+                .text()
+                .await?);
+        }
+
+        let key = request.url().to_string();
+        let cached = self.lookup(&key).await;
+
+        if let Some(entry) = cached.as_ref().filter(|entry| entry.is_fresh()) {
+            debug!("Cache hit for {}", key);
+            return Ok(entry.body.clone());
+        }
+
+        if let Some(etag) = cached.as_ref().and_then(|entry| entry.etag.as_deref()) {
+            if let Ok(etag) = HeaderValue::from_str(etag) {
+                request.headers_mut().insert(IF_NONE_MATCH, etag);
+            }
+        }
+
+        let resp = match client.execute(request).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                return match cached {
+                    Some(entry) => {
+                        warn!("Using stale response for {}: {}", key, err);
+                        Ok(entry.body)
+                    }
+                    None => Err(err.into()),
+                }
+            }
+        };
+        let lifetime = lifetime(resp.headers());
+
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            // Only revalidated requests can legitimately get a 304
+            let Some(mut entry) = cached else {
+                return Err(APIError::NotModified(key.into()));
+            };
+
+            debug!("{} has not been modified", key);
+            entry.expires = expires_in(lifetime.unwrap_or_default());
+            self.store(&key, entry.clone()).await;
+
+            return Ok(entry.body);
+        }
+
+        let resp = resp.error_for_status()?;
+        let etag = resp
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(String::from);
+        let body = resp.text().await?;
+
+        match lifetime {
+            Some(lifetime) => {
+                self.store(
+                    &key,
+                    CacheEntry {
+                        body: body.clone(),
+                        etag,
+                        expires: expires_in(lifetime),
+                    },
+                )
+                .await
+            }
+            None => debug!("Not caching {}", key),
+        }
+
+        Ok(body)
+    }
+
+    async fn lookup(&self, key: &str) -> Option<CacheEntry> {
+        if let Some(entry) = self.memory.lock().unwrap().get(key) {
+            return Some(entry);
+        }
+
+        let path = self.path(key)?;
+        let entry: CacheEntry = match fs::read(&path).await {
+            Ok(bytes) => match serde_json::from_slice(&bytes) {
+                Ok(entry) => entry,
+                Err(err) => {
+                    warn!("Ignoring corrupt cache file {}: {}", path.display(), err);
+                    return None;
+                }
+            },
+            Err(_) => return None,
+        };
+
+        self.memory
+            .lock()
+            .unwrap()
+            .put(key, entry.clone(), self.capacity);
+        Some(entry)
+    }
+
+    async fn store(&self, key: &str, entry: CacheEntry) {
+        if let Some(path) = self.path(key) {
+            let written = match path.parent() {
+                Some(dir) => fs::create_dir_all(dir).await,
+                None => Ok(()),
+            };
+            let written = match (written, serde_json::to_vec(&entry)) {
+                (Ok(()), Ok(bytes)) => fs::write(&path, bytes).await,
+                (Err(err), _) => Err(err),
+                (_, Err(err)) => Err(err.into()),
+            };
+
+            match written {
+                Ok(()) => self.written(path).await,
+                Err(err) => warn!("Couldn't write cache file {}: {}", path.display(), err),
+            }
+        }
+
+        self.memory.lock().unwrap().put(key, entry, self.capacity);
+    }
+
+    /// Records that `path` was just written, then deletes the cache
+    /// files written longest ago until at most `disk_capacity` are left
+    async fn written(&self, path: PathBuf) {
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        let files = self
+            .disk
+            .get_or_init(|| async { Mutex::new(list_files(dir).await) })
+            .await;
+
+        let excess = {
+            let mut files = files.lock().unwrap();
+            files.touch(path);
+
+            let mut excess = Vec::new();
+            while files.len() > self.disk_capacity {
+                match files.pop_oldest() {
+                    Some(oldest) => excess.push(oldest),
+                    None => break,
+                }
+            }
+
+            excess
+        };
+
+        if !excess.is_empty() {
+            debug!("Removing {} old cache files", excess.len());
+        }
+        for path in excess {
+            if let Err(err) = fs::remove_file(&path).await {
+                warn!("Couldn't remove cache file {}: {}", path.display(), err);
+            }
+        }
+    }
+
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+
+        Some(dir.join(format!("{}{:x}.json", FILE_PREFIX, Sha256::digest(key))))
+    }
+}
+
+/// The cache files already in `dir`, by when they were last modified
+async fn list_files(dir: &Path) -> Recency<PathBuf> {
+    let mut files = Vec::new();
+    if let Ok(mut entries) = fs::read_dir(dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if !is_cache_file(&path) {
+                continue;
+            }
+
+            if let Ok(modified) = entry.metadata().await.and_then(|meta| meta.modified()) {
+                files.push((modified, path));
+            }
+        }
+    }
+    files.sort();
+
+    let mut recency = Recency::default();
+    for (_, path) in files {
+        recency.touch(path);
+    }
+
+    recency
+}
+
+fn is_cache_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(FILE_PREFIX) && name.ends_with(".json"))
+}
+
+/// What the functions of this crate send their requests with:
+/// a plain `Client`, or a `CachedClient`
+pub trait ModrinthClient {
+    /// The client requests are sent with
+    fn http(&self) -> &Client;
+
+    /// The cache requests go through, if any
+    fn cache(&self) -> Option<&Cache> {
+        None
+    }
+}
+
+impl ModrinthClient for Client {
+    fn http(&self) -> &Client {
+        self
+    }
+}
+
+#[derive(Clone)]
+/// A `Client` whose requests to the search, project and version
+/// endpoints go through a `Cache`. The cache can be shared
+/// between several clients
+/// ## Usage
+/// ```
+/// use std::sync::Arc;
+///
+/// use modrinth::{get_client, get_project, Cache, CachedClient};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let cache = Cache::new(256).with_dir(std::env::temp_dir().join("modrinth-cache"));
+///     let client = CachedClient::new(get_client().await.unwrap(), Arc::new(cache));
+///
+///     let project = get_project(&client, "kontraption").await.unwrap();
+///     let cached = get_project(&client, "kontraption").await.unwrap();
+///
+///     assert_eq!(project.id, cached.id);
+/// }
+/// ```
+pub struct CachedClient {
+    client: Client,
+    cache: Arc<Cache>,
+}
+
+impl CachedClient {
+    pub fn new(client: Client, cache: Arc<Cache>) -> Self {
+        info!("Caching responses");
+        Self { client, cache }
+    }
+}
+
+impl ModrinthClient for CachedClient {
+    fn http(&self) -> &Client {
+        &self.client
+    }
+
+    fn cache(&self) -> Option<&Cache> {
+        Some(&self.cache)
+    }
+}
+
+/// Sends `request` through the cache of `client`, if
+/// it has one, and deserializes the response body
+pub(crate) async fn get_json<C, T>(client: &C, request: RequestBuilder) -> Result<T, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    T: DeserializeOwned,
+{
+    let body = match client.cache() {
+        Some(cache) => cache.fetch(client.http(), request).await?,
+        None => request.send().await?.error_for_status()?.text().await?,
+    };
+
+    Ok(serde_json::from_str(&body)?)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    body: String,
+    etag: Option<String>,
+    /// UNIX timestamp after which the response has to be revalidated
+    expires: u64,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        now() < self.expires
+    }
+}
+
+#[derive(Default)]
+/// Cache entries keyed by URL, evicting the least recently used ones
+struct Lru {
+    entries: HashMap<String, CacheEntry>,
+    recency: Recency<String>,
+}
+
+impl Lru {
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        let entry = self.entries.get(key).cloned()?;
+        self.recency.touch(key.to_string());
+
+        Some(entry)
+    }
+
+    fn put(&mut self, key: &str, entry: CacheEntry, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        self.entries.insert(key.to_string(), entry);
+        self.recency.touch(key.to_string());
+
+        while self.entries.len() > capacity {
+            let Some(oldest) = self.recency.pop_oldest() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// Keys in the order they were last touched in. Every touch gets a
+/// new tick, so finding and moving a key never means walking the order
+struct Recency<K> {
+    ticks: HashMap<K, u64>,
+    order: BTreeMap<u64, K>,
+    next: u64,
+}
+
+impl<K> Default for Recency<K> {
+    fn default() -> Self {
+        Self {
+            ticks: HashMap::new(),
+            order: BTreeMap::new(),
+            next: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Recency<K> {
+    fn touch(&mut self, key: K) {
+        if let Some(tick) = self.ticks.insert(key.clone(), self.next) {
+            self.order.remove(&tick);
+        }
+
+        self.order.insert(self.next, key);
+        self.next += 1;
+    }
+
+    fn pop_oldest(&mut self) -> Option<K> {
+        let (_, key) = self.order.pop_first()?;
+        self.ticks.remove(&key);
+
+        Some(key)
+    }
+
+    fn len(&self) -> usize {
+        self.ticks.len()
+    }
+}
+
+/// How many seconds a response may be reused for according
+/// to its `Cache-Control` header, or `None` if it may not be
+/// stored at all. Responses without the header are stored,
+/// but revalidated every time
+fn lifetime(headers: &HeaderMap) -> Option<u64> {
+    let Some(cache_control) = headers
+        .get(CACHE_CONTROL)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return Some(0);
+    };
+
+    let mut max_age = 0;
+    for directive in cache_control.split(',').map(str::trim) {
+        match directive.split_once('=') {
+            Some(("max-age", age)) => max_age = age.trim_matches('"').parse().unwrap_or(0),
+            None if directive.eq_ignore_ascii_case("no-store") => return None,
+            None if directive.eq_ignore_ascii_case("no-cache") => return Some(0),
+            _ => {}
+        }
+    }
+
+    Some(max_age)
+}
+
+fn expires_in(lifetime: u64) -> u64 {
+    now().saturating_add(lifetime)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(body: &str) -> CacheEntry {
+        CacheEntry {
+            body: body.to_string(),
+            etag: Some("\"etag\"".to_string()),
+            expires: expires_in(60),
+        }
+    }
+
+    fn headers(cache_control: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_str(cache_control).unwrap());
+
+        headers
+    }
+
+    #[test]
+    fn lru_evicts_least_recently_used() {
+        let mut lru = Lru::default();
+        lru.put("a", entry("a"), 2);
+        lru.put("b", entry("b"), 2);
+        lru.get("a");
+        lru.put("c", entry("c"), 2);
+
+        assert!(lru.get("a").is_some());
+        assert!(lru.get("b").is_none());
+        assert!(lru.get("c").is_some());
+    }
+
+    #[test]
+    fn cache_control_lifetime() {
+        assert_eq!(lifetime(&HeaderMap::new()), Some(0));
+        assert_eq!(lifetime(&headers("public, max-age=300")), Some(300));
+        assert_eq!(lifetime(&headers("no-cache")), Some(0));
+        assert_eq!(lifetime(&headers("max-age=300, no-store")), None);
+    }
+
+    #[tokio::test]
+    async fn disk_entries_survive_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let url = "https://api.modrinth.com/v2/project/kontraption";
+        Cache::new(8)
+            .with_dir(dir.path())
+            .store(url, entry("{}"))
+            .await;
+
+        let restarted = Cache::new(8).with_dir(dir.path());
+        let cached = restarted.lookup(url).await.unwrap();
+
+        assert_eq!(cached.body, "{}");
+        assert!(cached.is_fresh());
+    }
+
+    #[tokio::test]
+    async fn disk_capacity() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("modpack.json"), b"{}")
+            .await
+            .unwrap();
+
+        let cache = Cache::new(8).with_dir(dir.path()).disk_capacity(2);
+        for project in ["kontraption", "sodium", "lithium"] {
+            let url = format!("https://api.modrinth.com/v2/project/{}", project);
+            cache.store(&url, entry("{}")).await;
+        }
+
+        let mut files = Vec::new();
+        let mut entries = fs::read_dir(dir.path()).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            files.push(entry.path());
+        }
+        let restarted = Cache::new(8).with_dir(dir.path());
+        let newest = restarted
+            .lookup("https://api.modrinth.com/v2/project/lithium")
+            .await;
+
+        assert_eq!(files.iter().filter(|file| is_cache_file(file)).count(), 2);
+        assert!(files.contains(&dir.path().join("modpack.json")));
+        assert!(newest.is_some());
+    }
+}
@@ -1,20 +1,57 @@
-use super::APIError;
+use std::collections::HashMap;
+
+use super::cache::ModrinthClient;
+use super::{APIError, VersionSelector};
 use crate::{
+    project::get_project_dependencies,
     types::{
+        id::{ProjectId, VersionId},
         query::VersionQuery,
-        version::{ResolvedVersionDependency, VersionDependency},
+        version::{DependencyType, LockedVersion, UnresolvedVersionDependency, VersionDependency},
     },
-    version::{get_version, get_versions},
+    version::{get_multiple_versions, get_versions},
     ModrinthProjectVersion,
 };
 use log::{debug, info, warn};
-use reqwest::Client;
 
-/// Recursively resolves dependencies, using `resolver` as a helper function
-/// to decide how the `resolve_dependencies` picks a version among a list.
-// ## Usage
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// What `resolve_dependencies` does with optional dependencies
+pub enum OptionalDependencies {
+    /// Leave optional dependencies out of the lock list
+    #[default]
+    Skip,
+    /// Resolve and install optional dependencies like required ones
+    Include,
+}
+
+#[derive(Debug, Default)]
+/// Options that change how `resolve_dependencies` walks the dependency graph
+pub struct ResolveOptions {
+    /// What to do with optional dependencies
+    pub optional: OptionalDependencies,
+    /// Fetch every version pinned by ID by the project's versions in one
+    /// request with `get_project_dependencies` before walking the graph,
+    /// instead of only fetching them as they are reached. Dependencies that
+    /// only name a project are still looked up with `get_versions`, since the
+    /// versions Modrinth prefetches aren't filtered by `version_params`
+    pub prefetch_pinned: bool,
+}
+
+/// Resolves the whole dependency graph of `project`, using `selector` to decide
+/// which version gets installed when a dependency doesn't name one. Use one of the
+/// built-in `VersionStrategy`s, or any function implementing `VersionSelector`.
+///
+/// Every project is resolved once, no matter how many versions depend on it, and
+/// versions referenced by ID are fetched in batches. Resolution fails if the graph
+/// has a cycle, if a resolved project is marked as incompatible by another, if two
+/// versions require different versions of the same project, if `selector` finds
+/// no acceptable version of a dependency, or if a resolved version has no files.
+///
+/// Returns a flat list of versions to install, where dependencies always come
+/// before their dependents and `project` comes last
+/// ## Usage
 /// ```
-/// use modrinth::{resolve_dependencies, get_versions, get_project, get_client, search_project, IndexBy, Loader, ProjectQueryBuilder, VersionQueryBuilder};
+/// use modrinth::{resolve_dependencies, get_versions, get_project, get_client, search_project, IndexBy, Loader, ProjectQueryBuilder, ResolveOptions, VersionQueryBuilder, VersionStrategy};
 ///
 /// #[tokio::main]
 /// async fn main() {
@@ -35,109 +72,590 @@ use reqwest::Client;
 ///         .loaders(vec![Loader::Fabric])
 ///         .build();
 ///
-///     let mut versions = get_versions(&client, &project, &v_query).await.unwrap();
-///     let version = versions.get_mut(0).unwrap();
+///     let versions = get_versions(&client, &project, &v_query).await.unwrap();
+///     let version = versions.first().unwrap();
 ///
-///     let _err = resolve_dependencies(&client, version, &v_query, |versions| {
-///         versions.into_iter().next().unwrap() // always select the first version listed
-///     })
-///     .await;
+///     let lock = resolve_dependencies(
+///         &client,
+///         version,
+///         &v_query,
+///         &ResolveOptions::default(),
+///         &VersionStrategy::PreferRelease,
+///     )
+///     .await
+///     .unwrap();
 ///
-///     assert!(version
-///         .dependencies
-///         .as_ref()
-///         .unwrap()
-///         .iter()
-///         .all(|dep| dep.is_resolved()));
+///     assert!(lock.len() > 1);
+///     assert_eq!(lock.last().unwrap().version_id, version.id);
 /// }
 /// ```
-pub async fn resolve_dependencies<F>(
-    client: &Client,
-    project: &mut ModrinthProjectVersion,
+pub async fn resolve_dependencies<C, S>(
+    client: &C,
+    project: &ModrinthProjectVersion,
     version_params: &VersionQuery,
-    resolver: F,
-) -> Result<(), APIError>
+    options: &ResolveOptions,
+    selector: &S,
+) -> Result<Vec<LockedVersion>, APIError>
 where
-    F: Fn(Vec<ModrinthProjectVersion>) -> ModrinthProjectVersion + Copy,
+    C: ModrinthClient + ?Sized,
+    S: VersionSelector + ?Sized,
 {
-    if project.dependencies.is_none() || project.dependencies.as_ref().is_some_and(|v| v.is_empty())
-    {
-        return Err(APIError::NoDependencies);
+    info!("Resolving dependencies for mod {}", project.name);
+    let mut known = HashMap::new();
+    if options.prefetch_pinned {
+        let prefetched = get_project_dependencies(client, &*project.project_id).await?;
+        debug!("Prefetched {} pinned versions", prefetched.versions.len());
+
+        known.extend(
+            prefetched
+                .versions
+                .into_iter()
+                .map(|version| (version.id.clone(), version)),
+        );
     }
 
-    info!("Resolving dependnecies for mod {}", project.name);
-    for dependency in project.dependencies.as_mut().unwrap().iter_mut() {
-        let unresolved_dependency = match dependency {
-            VersionDependency::Resolved(ver) => {
-                Err(APIError::ResolvedDependency(ver.dependency.name.clone()))
+    let mut graph = DependencyGraph::new(options);
+    let mut frontier = graph.insert(None, project.clone(), None, true)?;
+
+    while !frontier.is_empty() {
+        debug!("Resolving {} dependencies", frontier.len());
+
+        let mut by_version = Vec::new();
+        let mut by_project = Vec::new();
+        for pending in frontier.drain(..) {
+            if graph.link_existing(&pending)? {
+                continue;
             }
-            VersionDependency::Unresolved(ver) => Ok(ver),
-        }?;
 
-        if unresolved_dependency.version_id.is_none() && unresolved_dependency.project_id.is_none()
-        {
-            return Err(APIError::UnresolvableDependency);
+            if pending.dependency.version_id.is_some() {
+                by_version.push(pending);
+            } else {
+                by_project.push(pending);
+            }
         }
 
-        let mut resolved_version = if unresolved_dependency.version_id.is_some() {
-            get_version(client, unresolved_dependency).await?
-        } else {
-            warn!(
-                "No version ID supplied for project {:?}",
-                unresolved_dependency.project_id.as_ref().unwrap()
+        let mut resolved = Vec::with_capacity(by_version.len() + by_project.len());
+
+        let missing = by_version
+            .iter()
+            .map(|pending| &pending.dependency)
+            .filter(|dependency| !known.contains_key(dependency.version_id.as_ref().unwrap()))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            known.extend(
+                get_multiple_versions(client, &missing)
+                    .await?
+                    .into_iter()
+                    .map(|version| (version.id.clone(), version)),
             );
-            let version_list = get_versions(client, unresolved_dependency, version_params).await?;
+        }
 
-            if version_list.len() == 1 {
-                debug!("Only 1 version found, returning that");
-                version_list.into_iter().next().unwrap()
-            } else {
-                debug!(
-                    "{} versions found with the matching criterion",
-                    version_list.len()
-                );
-                resolver(version_list)
+        for pending in by_version {
+            let version_id = pending.dependency.version_id.as_ref().unwrap();
+            let version = known
+                .get(version_id)
+                .cloned()
+                .ok_or_else(|| APIError::MissingVersion(version_id.clone()))?;
+
+            resolved.push((pending, version));
+        }
+
+        let mut picked: HashMap<ProjectId, ModrinthProjectVersion> = HashMap::new();
+        for pending in by_project {
+            let project_id = pending.dependency.project_id.clone().unwrap();
+            warn!("No version ID supplied for project {:?}", project_id);
+
+            if let Some(version) = picked.get(&project_id) {
+                resolved.push((pending, version.clone()));
+                continue;
             }
-        };
 
-        if resolved_version
-            .dependencies
-            .as_ref()
-            .is_some_and(|deps| !deps.is_empty())
-        {
+            let version_list = get_versions(client, &pending.dependency, version_params).await?;
             debug!(
-                "Resolving dependency {}'s dependencies",
-                resolved_version.name
+                "{} versions found with the matching criterion",
+                version_list.len()
+            );
+            let version = selector.select(&project_id, version_list)?;
+
+            picked.insert(project_id, version.clone());
+            resolved.push((pending, version));
+        }
+
+        for (pending, version) in resolved {
+            info!(
+                "Mod with ID {} resolved to {}",
+                version.project_id, version.name
             );
-            Box::pin(resolve_dependencies(
-                client,
-                &mut resolved_version,
-                version_params,
-                resolver,
-            ))
-            .await?;
-        }
-
-        info!(
-            "Mod with ID {} resolved to {}",
-            resolved_version.id, resolved_version.name
+            let pinned = pending.dependency.version_id.is_some();
+            frontier.extend(graph.insert(
+                Some(pending.parent),
+                version,
+                Some(pending.dependency.dependency_type),
+                pinned,
+            )?);
+        }
+    }
+
+    graph.check_incompatibilities()?;
+    let lock = graph.into_lock(&project.project_id)?;
+
+    info!("All dependencies resolved!");
+    Ok(lock)
+}
+
+/// A dependency waiting to be fetched, along with the project that requires it
+struct PendingDependency {
+    parent: ProjectId,
+    dependency: UnresolvedVersionDependency,
+}
+
+struct DependencyNode {
+    version: ModrinthProjectVersion,
+    dependency_type: Option<DependencyType>,
+    dependencies: Vec<ProjectId>,
+}
+
+/// A version marking another project or version as incompatible
+struct Incompatibility {
+    declared_by: ProjectId,
+    project_id: Option<ProjectId>,
+    version_id: Option<VersionId>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Visiting,
+    Visited,
+}
+
+/// Resolved versions keyed by project ID
+struct DependencyGraph<'a> {
+    options: &'a ResolveOptions,
+    nodes: HashMap<ProjectId, DependencyNode>,
+    incompatibilities: Vec<Incompatibility>,
+}
+
+impl<'a> DependencyGraph<'a> {
+    fn new(options: &'a ResolveOptions) -> Self {
+        Self {
+            options,
+            nodes: HashMap::new(),
+            incompatibilities: Vec::new(),
+        }
+    }
+
+    /// Links `pending` to an already resolved node, if there is one.
+    /// Fails if `pending` needs another version of the node's project
+    fn link_existing(&mut self, pending: &PendingDependency) -> Result<bool, APIError> {
+        let existing = self
+            .nodes
+            .iter()
+            .find(|(project_id, node)| {
+                pending.dependency.project_id.as_ref() == Some(*project_id)
+                    || pending.dependency.version_id.as_ref() == Some(&node.version.id)
+            })
+            .map(|(project_id, _)| project_id.clone());
+
+        let Some(project_id) = existing else {
+            return Ok(false);
+        };
+
+        if let Some(version_id) = pending.dependency.version_id.as_ref() {
+            self.check_pinned(&pending.parent, &project_id, version_id)?;
+        }
+
+        debug!("Project {} already resolved", project_id);
+        self.link(
+            &pending.parent,
+            project_id,
+            pending.dependency.dependency_type,
         );
-        *dependency = VersionDependency::Resolved(ResolvedVersionDependency {
-            dependency: resolved_version,
-            dependency_type: unresolved_dependency.dependency_type.clone(),
-        });
+        Ok(true)
+    }
+
+    /// Fails if `project_id` was resolved to another version than `version_id`,
+    /// which `parent` needs exactly
+    fn check_pinned(
+        &self,
+        parent: &ProjectId,
+        project_id: &ProjectId,
+        version_id: &VersionId,
+    ) -> Result<(), APIError> {
+        let resolved = &self.nodes[project_id].version;
+        if resolved.id == *version_id {
+            return Ok(());
+        }
+
+        Err(APIError::ConflictingVersions {
+            dependent: self.nodes[parent].version.name.clone(),
+            required: version_id.to_string().into(),
+            resolved: resolved.name.clone(),
+        })
+    }
+
+    fn link(&mut self, parent: &ProjectId, project_id: ProjectId, dependency_type: DependencyType) {
+        if let Some(node) = self.nodes.get_mut(&project_id) {
+            if dependency_type == DependencyType::Required {
+                node.dependency_type = Some(DependencyType::Required);
+            }
+        }
+
+        if let Some(parent) = self.nodes.get_mut(parent) {
+            if !parent.dependencies.contains(&project_id) {
+                parent.dependencies.push(project_id);
+            }
+        }
     }
 
-    info!("All dependnecies resolved!");
-    Ok(())
+    /// Adds `version` to the graph and returns its dependencies that still
+    /// need to be fetched. A `pinned` version was asked for by its ID, so it
+    /// can't stand in for another version of its project
+    fn insert(
+        &mut self,
+        parent: Option<ProjectId>,
+        version: ModrinthProjectVersion,
+        dependency_type: Option<DependencyType>,
+        pinned: bool,
+    ) -> Result<Vec<PendingDependency>, APIError> {
+        let project_id = version.project_id.clone();
+
+        if let (true, Some(parent)) = (pinned, parent.as_ref()) {
+            if self.nodes.contains_key(&project_id) {
+                self.check_pinned(parent, &project_id, &version.id)?;
+            }
+        }
+
+        if let Some(parent) = parent.as_ref() {
+            self.link(parent, project_id.clone(), dependency_type.unwrap());
+        }
+
+        if self.nodes.contains_key(&project_id) {
+            debug!("Project {} already resolved", project_id);
+            return Ok(Vec::new());
+        }
+
+        let dependencies = version.dependencies.clone().unwrap_or_default();
+        self.nodes.insert(
+            project_id.clone(),
+            DependencyNode {
+                version,
+                dependency_type,
+                dependencies: Vec::new(),
+            },
+        );
+
+        let mut pending = Vec::new();
+        for dependency in dependencies {
+            let dependency = match dependency {
+                VersionDependency::Resolved(resolved) => {
+                    pending.extend(self.insert(
+                        Some(project_id.clone()),
+                        resolved.dependency,
+                        Some(resolved.dependency_type),
+                        true,
+                    )?);
+                    continue;
+                }
+                VersionDependency::Unresolved(dependency) => dependency,
+            };
+
+            match dependency.dependency_type {
+                DependencyType::Embedded => continue,
+                DependencyType::Incompatible => {
+                    self.incompatibilities.push(Incompatibility {
+                        declared_by: project_id.clone(),
+                        project_id: dependency.project_id,
+                        version_id: dependency.version_id,
+                    });
+                    continue;
+                }
+                DependencyType::Optional if self.options.optional == OptionalDependencies::Skip => {
+                    continue
+                }
+                DependencyType::Optional | DependencyType::Required => {}
+            }
+
+            if dependency.version_id.is_none() && dependency.project_id.is_none() {
+                if dependency.dependency_type == DependencyType::Required {
+                    return Err(APIError::UnresolvableDependency);
+                }
+
+                warn!(
+                    "Skipping optional dependency {:?} with no project or version ID",
+                    dependency.file_name
+                );
+                continue;
+            }
+
+            pending.push(PendingDependency {
+                parent: project_id.clone(),
+                dependency,
+            });
+        }
+
+        Ok(pending)
+    }
+
+    fn check_incompatibilities(&self) -> Result<(), APIError> {
+        for incompatibility in self.incompatibilities.iter() {
+            let conflict = self.nodes.iter().find(|(project_id, node)| {
+                incompatibility.project_id.as_ref() == Some(*project_id)
+                    || incompatibility.version_id.as_ref() == Some(&node.version.id)
+            });
+
+            if let Some((_, node)) = conflict {
+                return Err(APIError::IncompatibleDependency(
+                    self.nodes[&incompatibility.declared_by]
+                        .version
+                        .name
+                        .clone(),
+                    node.version.name.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Flattens the graph so that every version comes after its dependencies
+    fn into_lock(mut self, root: &ProjectId) -> Result<Vec<LockedVersion>, APIError> {
+        let mut states = HashMap::new();
+        let mut path = Vec::new();
+        let mut order = Vec::new();
+
+        self.visit(root, &mut states, &mut path, &mut order)?;
+
+        let mut lock = Vec::with_capacity(order.len());
+        for project_id in order {
+            let node = self.nodes.remove(&project_id).unwrap();
+            let file = node
+                .version
+                .primary_file()
+                .cloned()
+                .ok_or_else(|| APIError::MissingFile(node.version.name.clone()))?;
+
+            lock.push(LockedVersion {
+                project_id,
+                version_id: node.version.id,
+                name: node.version.name,
+                version_number: node.version.version_number,
+                dependency_type: node.dependency_type,
+                file,
+            });
+        }
+
+        Ok(lock)
+    }
+
+    fn visit(
+        &self,
+        project_id: &ProjectId,
+        states: &mut HashMap<ProjectId, VisitState>,
+        path: &mut Vec<ProjectId>,
+        order: &mut Vec<ProjectId>,
+    ) -> Result<(), APIError> {
+        match states.get(project_id) {
+            Some(VisitState::Visited) => return Ok(()),
+            Some(VisitState::Visiting) => {
+                let start = path.iter().position(|id| id == project_id).unwrap();
+                let cycle = path[start..]
+                    .iter()
+                    .chain(std::iter::once(project_id))
+                    .map(|id| self.nodes[id].version.name.clone())
+                    .collect();
+
+                return Err(APIError::DependencyCycle(cycle));
+            }
+            None => {}
+        }
+
+        states.insert(project_id.clone(), VisitState::Visiting);
+        path.push(project_id.clone());
+
+        for dependency in self.nodes[project_id].dependencies.iter() {
+            self.visit(dependency, states, path, order)?;
+        }
+
+        path.pop();
+        states.insert(project_id.clone(), VisitState::Visited);
+        order.push(project_id.clone());
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::api::test_util::version;
+    use crate::types::version::ResolvedVersionDependency;
     use crate::{
-        get_client, search_project, IndexBy, Loader, ProjectQueryBuilder, VersionQueryBuilder,
+        get_client, search_project, IndexBy, Loader, NoAcceptableVersion, ProjectQueryBuilder,
+        VersionQueryBuilder, VersionStrategy,
     };
+    use std::collections::HashSet;
+
+    fn resolved(version: ModrinthProjectVersion) -> VersionDependency {
+        VersionDependency::Resolved(ResolvedVersionDependency {
+            dependency: version,
+            dependency_type: DependencyType::Required,
+        })
+    }
+
+    fn unresolved(
+        project_id: &str,
+        version_id: Option<&str>,
+        dependency_type: DependencyType,
+    ) -> UnresolvedVersionDependency {
+        UnresolvedVersionDependency {
+            version_id: version_id.map(|id| id.parse().unwrap()),
+            project_id: Some(project_id.parse().unwrap()),
+            file_name: None,
+            dependency_type,
+        }
+    }
+
+    fn project(id: &str) -> ProjectId {
+        id.parse().unwrap()
+    }
+
+    #[test]
+    fn lock_order() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let library = version("lib1", "library", vec![]);
+        let api = version("api1", "api", vec![resolved(library.clone())]);
+        let root = version("root1", "root", vec![resolved(api), resolved(library)]);
+        let pending = graph.insert(None, root, None, true).unwrap();
+        assert!(pending.is_empty());
+
+        let lock = graph.into_lock(&project("root")).unwrap();
+        let names = lock.iter().map(|locked| &*locked.name).collect::<Vec<_>>();
+
+        assert_eq!(names, ["lib1", "api1", "root1"]);
+    }
+
+    #[test]
+    fn cycle() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let root = version("root1", "root", vec![]);
+        let api = version("api1", "api", vec![resolved(root.clone())]);
+        let root = ModrinthProjectVersion {
+            dependencies: Some(vec![resolved(api)]),
+            ..root
+        };
+        graph.insert(None, root, None, true).unwrap();
+
+        assert!(matches!(
+            graph.into_lock(&project("root")),
+            Err(APIError::DependencyCycle(cycle)) if cycle.len() == 3
+        ));
+    }
+
+    #[test]
+    fn incompatibility() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let api = version("api1", "api", vec![]);
+        let other = version(
+            "other1",
+            "other",
+            vec![VersionDependency::Unresolved(unresolved(
+                "api",
+                None,
+                DependencyType::Incompatible,
+            ))],
+        );
+        let root = version("root1", "root", vec![resolved(api), resolved(other)]);
+        graph.insert(None, root, None, true).unwrap();
+
+        assert!(matches!(
+            graph.check_incompatibilities(),
+            Err(APIError::IncompatibleDependency(declared_by, conflict))
+                if &*declared_by == "other1" && &*conflict == "api1"
+        ));
+    }
+
+    #[test]
+    fn conflicting_versions() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let other = version(
+            "other1",
+            "other",
+            vec![resolved(version("lib2", "library", vec![]))],
+        );
+        let root = version(
+            "root1",
+            "root",
+            vec![
+                resolved(version("lib1", "library", vec![])),
+                resolved(other),
+            ],
+        );
+
+        assert!(matches!(
+            graph.insert(None, root, None, true),
+            Err(APIError::ConflictingVersions { dependent, .. }) if &*dependent == "other1"
+        ));
+    }
+
+    #[test]
+    fn conflicting_pending_version() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let root = version(
+            "root1",
+            "root",
+            vec![resolved(version("lib1", "library", vec![]))],
+        );
+        graph.insert(None, root, None, true).unwrap();
+
+        let any_version = PendingDependency {
+            parent: project("root"),
+            dependency: unresolved("library", None, DependencyType::Required),
+        };
+        assert!(graph.link_existing(&any_version).unwrap());
+
+        let same_version = PendingDependency {
+            parent: project("root"),
+            dependency: unresolved("library", Some("lib1"), DependencyType::Required),
+        };
+        assert!(graph.link_existing(&same_version).unwrap());
+
+        let other_version = PendingDependency {
+            parent: project("root"),
+            dependency: unresolved("library", Some("lib2"), DependencyType::Required),
+        };
+        assert!(matches!(
+            graph.link_existing(&other_version),
+            Err(APIError::ConflictingVersions { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_file() {
+        let options = ResolveOptions::default();
+        let mut graph = DependencyGraph::new(&options);
+
+        let library = ModrinthProjectVersion {
+            files: Vec::new(),
+            ..version("lib1", "library", vec![])
+        };
+        let root = version("root1", "root", vec![resolved(library)]);
+        graph.insert(None, root, None, true).unwrap();
+
+        assert!(matches!(
+            graph.into_lock(&project("root")),
+            Err(APIError::MissingFile(name)) if &*name == "lib1"
+        ));
+    }
 
     #[tokio::test]
     async fn check_dep_resolution() {
@@ -158,19 +676,71 @@ mod test {
             .loaders(vec![Loader::Fabric])
             .build();
 
-        let mut versions = get_versions(&client, &project, &v_query).await.unwrap();
-        let version = versions.get_mut(0).unwrap();
+        let versions = get_versions(&client, &project, &v_query).await.unwrap();
+        let version = versions.first().unwrap();
 
-        let _err = resolve_dependencies(&client, version, &v_query, |versions| {
-            versions.into_iter().next().unwrap()
-        })
-        .await;
+        let lock = resolve_dependencies(
+            &client,
+            version,
+            &v_query,
+            &ResolveOptions::default(),
+            &|project: &str, versions: Vec<ModrinthProjectVersion>| {
+                versions
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| NoAcceptableVersion::new(project, "no versions listed"))
+            },
+        )
+        .await
+        .unwrap();
 
-        assert!(version
-            .dependencies
-            .as_ref()
-            .unwrap()
+        let project_ids = lock
             .iter()
-            .all(|dep| dep.is_resolved()));
+            .map(|locked| locked.project_id.clone())
+            .collect::<HashSet<_>>();
+
+        assert_eq!(project_ids.len(), lock.len());
+        assert_eq!(lock.last().unwrap().version_id, version.id);
+    }
+
+    #[tokio::test]
+    async fn check_prefetched_dep_resolution() {
+        let client = get_client().await.unwrap();
+
+        let v_query = VersionQueryBuilder::new()
+            .featured(true)
+            .versions(vec!["1.20.1"])
+            .loaders(vec![Loader::Fabric])
+            .build();
+
+        let versions = get_versions(&client, "botaniacombat", &v_query)
+            .await
+            .unwrap();
+        let version = versions.first().unwrap();
+
+        let options = ResolveOptions {
+            prefetch_pinned: true,
+            ..Default::default()
+        };
+        let prefetched = resolve_dependencies(
+            &client,
+            version,
+            &v_query,
+            &options,
+            &VersionStrategy::PreferRelease,
+        )
+        .await
+        .unwrap();
+        let crawled = resolve_dependencies(
+            &client,
+            version,
+            &v_query,
+            &ResolveOptions::default(),
+            &VersionStrategy::PreferRelease,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(prefetched.len(), crawled.len());
     }
 }
@@ -0,0 +1,270 @@
+use std::path::{Path, PathBuf};
+
+use super::APIError;
+use crate::types::version::{is_plain_file_name, VersionFile};
+use crate::ModrinthProjectVersion;
+use log::{debug, info, warn};
+use reqwest::Client;
+use sha2::{Digest, Sha512};
+use tokio::fs::{self, File};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// How many times a download is attempted before giving up on a hash mismatch
+const MAX_ATTEMPTS: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+/// What `download_file` did to get the file on disk
+pub enum DownloadStatus {
+    /// The file was downloaded and its hash verified
+    Downloaded,
+    /// A file with a matching hash was already at the destination
+    AlreadyPresent,
+}
+
+/// Downloads the primary file of `version` into the directory `dir`,
+/// keeping the file's name. Names that would land outside of `dir`
+/// are rejected. See `download_file` for details
+/// ## Usage
+/// ```
+/// use modrinth::{download_version, get_client, get_versions, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let v_query = VersionQueryBuilder::new().build();
+///     let versions = get_versions(&client, "kontraption", &v_query).await.unwrap();
+///     let version = versions.first().unwrap();
+///
+///     let dir = std::env::temp_dir();
+///     let res = download_version(&client, version, &dir, |downloaded, total| {
+///         println!("{}/{} bytes", downloaded, total);
+///     })
+///     .await;
+///
+///     assert!(res.is_ok());
+/// }
+/// ```
+pub async fn download_version<P, F>(
+    client: &Client,
+    version: &ModrinthProjectVersion,
+    dir: P,
+    progress: F,
+) -> Result<(PathBuf, DownloadStatus), APIError>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let file = version.primary_file().ok_or(APIError::NoFiles)?;
+    let path = dir.as_ref().join(checked_file_name(&file.filename)?);
+
+    let status = download_file(client, file, &path, progress).await?;
+    Ok((path, status))
+}
+
+/// Streams `file` to `path`, calling `progress` with the number of bytes
+/// downloaded so far and the total size of the file after every chunk.
+///
+/// The download is written next to `path` and only moved there once its
+/// SHA512 hash matches the one reported by Modrinth, retrying up to three
+/// times on a mismatch. If `path` already holds a file with a matching hash,
+/// nothing is downloaded. A download that fails midway is deleted.
+pub async fn download_file<P, F>(
+    client: &Client,
+    file: &VersionFile,
+    path: P,
+    mut progress: F,
+) -> Result<DownloadStatus, APIError>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let path = path.as_ref();
+
+    if fs::try_exists(path).await? {
+        if hash_file(path)
+            .await?
+            .eq_ignore_ascii_case(&file.hashes.sha512)
+        {
+            info!("{} is already downloaded", file.filename);
+            return Ok(DownloadStatus::AlreadyPresent);
+        }
+
+        warn!("{} exists but its hash doesn't match", path.display());
+    }
+
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    let part = PathBuf::from(part);
+
+    let mut actual = String::new();
+    for attempt in 1..=MAX_ATTEMPTS {
+        info!(
+            "Downloading {} (attempt {}/{})",
+            file.filename, attempt, MAX_ATTEMPTS
+        );
+        actual = stream_to(client, file, &part, &mut progress).await?;
+
+        if actual.eq_ignore_ascii_case(&file.hashes.sha512) {
+            fs::rename(&part, path).await?;
+            debug!("Saved {} to {}", file.filename, path.display());
+
+            return Ok(DownloadStatus::Downloaded);
+        }
+
+        warn!(
+            "Hash mismatch for {}: expected {}, got {}",
+            file.filename, file.hashes.sha512, actual
+        );
+    }
+
+    fs::remove_file(&part).await?;
+    Err(APIError::HashMismatch {
+        file: file.filename.clone(),
+        expected: file.hashes.sha512.clone(),
+        actual,
+    })
+}
+
+/// Writes the body of `file` to `path` and returns its SHA512 hash,
+/// removing whatever was written if the download fails
+async fn stream_to<F>(
+    client: &Client,
+    file: &VersionFile,
+    path: &Path,
+    progress: &mut F,
+) -> Result<String, APIError>
+where
+    F: FnMut(u64, u64),
+{
+    let hash = write_hashed(client, file, path, progress).await;
+    if hash.is_err() {
+        let _ = fs::remove_file(path).await;
+    }
+
+    hash
+}
+
+async fn write_hashed<F>(
+    client: &Client,
+    file: &VersionFile,
+    path: &Path,
+    progress: &mut F,
+) -> Result<String, APIError>
+where
+    F: FnMut(u64, u64),
+{
+    let mut resp = client.get(&*file.url).send().await?.error_for_status()?;
+    let total = resp.content_length().unwrap_or(file.size as u64);
+
+    let mut out = File::create(path).await?;
+    let mut hasher = Sha512::new();
+    let mut downloaded = 0;
+
+    while let Some(chunk) = resp.chunk().await? {
+        hasher.update(&chunk);
+        out.write_all(&chunk).await?;
+
+        downloaded += chunk.len() as u64;
+        progress(downloaded, total);
+    }
+    out.flush().await?;
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+async fn hash_file(path: &Path) -> Result<String, APIError> {
+    let mut file = File::open(path).await?;
+    let mut hasher = Sha512::new();
+    let mut buf = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// `name`, if joining it to a directory stays inside of it
+fn checked_file_name(name: &str) -> Result<&str, APIError> {
+    if is_plain_file_name(name) {
+        Ok(name)
+    } else {
+        Err(APIError::InvalidFileName(name.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{get_client, get_versions, VersionQueryBuilder};
+
+    #[test]
+    fn check_file_names() {
+        assert!(checked_file_name("sodium-0.5.8.jar").is_ok());
+
+        for name in [
+            "",
+            ".",
+            "..",
+            "../sodium.jar",
+            "mods/sodium.jar",
+            "/etc/passwd",
+        ] {
+            assert!(
+                matches!(checked_file_name(name), Err(APIError::InvalidFileName(_))),
+                "{:?} was accepted",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn check_hash_file() {
+        let path = std::env::temp_dir().join("modrinth-check-hash-file");
+        fs::write(&path, b"abc").await.unwrap();
+
+        let hash = hash_file(&path).await.unwrap();
+        fs::remove_file(&path).await.unwrap();
+
+        // SHA512 test vector for "abc"
+        assert_eq!(
+            hash,
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_download_version() {
+        let client = get_client().await.unwrap();
+
+        let v_query = VersionQueryBuilder::new().build();
+        let versions = get_versions(&client, "kontraption", &v_query)
+            .await
+            .unwrap();
+        let version = versions.first().unwrap();
+
+        let dir = std::env::temp_dir().join("modrinth-check-download-version");
+        let _ = fs::remove_dir_all(&dir).await;
+        fs::create_dir_all(&dir).await.unwrap();
+
+        let (path, status) = download_version(&client, version, &dir, |_, _| {})
+            .await
+            .unwrap();
+        assert!(path.exists());
+        assert_eq!(status, DownloadStatus::Downloaded);
+
+        let (_, status) = download_version(&client, version, &dir, |_, _| {})
+            .await
+            .unwrap();
+        assert_eq!(status, DownloadStatus::AlreadyPresent);
+
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "api"), allow(unused_imports, dead_code))]
 
-use std::{rc::Rc, time::Duration};
+use std::{sync::Arc, time::Duration};
 
 #[cfg(feature = "api")]
 use log::{debug, error, info};
@@ -9,21 +9,56 @@ use reqwest::Client;
 #[cfg(feature = "api")]
 use thiserror::Error;
 
+#[cfg(feature = "api")]
+use crate::VersionId;
+
+#[cfg(feature = "api")]
+pub mod cache;
 #[cfg(feature = "api")]
 pub mod dependency;
 #[cfg(feature = "api")]
+pub mod download;
+#[cfg(feature = "api")]
 pub mod project;
 #[cfg(feature = "api")]
+pub mod strategy;
+#[cfg(feature = "api")]
+pub mod tag;
+#[cfg(all(test, feature = "api"))]
+mod test_util;
+#[cfg(feature = "api")]
+pub mod update;
+#[cfg(feature = "api")]
+pub mod user;
+#[cfg(feature = "api")]
 pub mod version;
 
 #[cfg(feature = "api")]
-pub use dependency::resolve_dependencies;
+pub use cache::{Cache, CachedClient, ModrinthClient};
 #[cfg(feature = "api")]
-pub use project::{get_project, search_project};
+pub use dependency::{resolve_dependencies, OptionalDependencies, ResolveOptions};
 #[cfg(feature = "api")]
-pub use version::get_versions;
+pub use download::{download_file, download_version, DownloadStatus};
+#[cfg(feature = "api")]
+pub use project::{get_multiple_projects, get_project, get_project_dependencies, search_project};
+#[cfg(feature = "api")]
+pub use strategy::{NoAcceptableVersion, VersionSelector, VersionStrategy};
+#[cfg(feature = "api")]
+pub use tag::{get_categories, get_game_versions, get_licenses, get_loaders};
+#[cfg(feature = "api")]
+pub use update::{get_followed_updates, get_new_versions, ProjectUpdates};
+#[cfg(feature = "api")]
+pub use user::{
+    get_followed_projects, get_notifications, get_project_members, get_project_team,
+    get_team_members, get_user, get_user_projects, get_version_author,
+};
+#[cfg(feature = "api")]
+pub use version::{get_multiple_versions, get_version, get_versions};
 
 const ENDPOINT: &str = "https://api.modrinth.com";
+/// How many IDs are sent per request by the `get_multiple_*` functions,
+/// keeping the query string well under common URL length limits
+const BATCH_SIZE: usize = 100;
 
 #[cfg(feature = "api")]
 #[derive(Debug, Error)]
@@ -31,14 +66,55 @@ pub enum APIError {
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
 
-    #[error("dependency already resolved: {0}")]
-    ResolvedDependency(Rc<str>),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
 
-    #[error("provided mod has no dependencies")]
-    NoDependencies,
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
 
     #[error("provided mod has unresolvable dependencies")]
     UnresolvableDependency,
+
+    #[error("dependency version {0} doesn't exist")]
+    MissingVersion(VersionId),
+
+    #[error("dependency cycle detected: {}", .0.join(" -> "))]
+    DependencyCycle(Vec<Arc<str>>),
+
+    #[error("{0} is incompatible with {1}")]
+    IncompatibleDependency(Arc<str>, Arc<str>),
+
+    #[error("{dependent} needs version {required} of a project already resolved to {resolved}")]
+    ConflictingVersions {
+        dependent: Arc<str>,
+        required: Arc<str>,
+        resolved: Arc<str>,
+    },
+
+    #[error(transparent)]
+    NoAcceptableVersion(#[from] strategy::NoAcceptableVersion),
+
+    #[error("provided version has no files")]
+    NoFiles,
+
+    #[error("version {0} has no files to download")]
+    MissingFile(Arc<str>),
+
+    #[error("refusing to save a download as {0:?}, it isn't a plain file name")]
+    InvalidFileName(Arc<str>),
+
+    #[error("hash mismatch for {file}: expected {expected}, got {actual}")]
+    HashMismatch {
+        file: Arc<str>,
+        expected: Arc<str>,
+        actual: String,
+    },
+
+    #[error("provided item has no {0} id")]
+    MissingId(&'static str),
+
+    #[error("modrinth answered {0} with 304 Not Modified, but nothing was cached for it")]
+    NotModified(Arc<str>),
 }
 
 #[cfg(feature = "api")]
@@ -118,7 +194,10 @@ pub async fn get_client() -> Option<Client> {
 #[cfg(test)]
 #[cfg(feature = "api")]
 mod tests {
-    use super::check_api;
+    use super::*;
+    use crate::{ProjectQueryBuilder, ResolveOptions, VersionQueryBuilder, VersionStrategy};
+
+    fn assert_send<T: Send>(_: T) {}
 
     #[tokio::test]
     async fn check_api_works() {
@@ -129,4 +208,35 @@ mod tests {
 
         assert!(labrinth_responding);
     }
+
+    #[test]
+    fn futures_are_send() {
+        let client = Client::new();
+        let v_query = VersionQueryBuilder::new().build();
+        let versions = get_versions(&client, "kontraption", &v_query);
+
+        assert_send(search_project(&client, &ProjectQueryBuilder::new().build()));
+        assert_send(get_project(&client, "kontraption"));
+        assert_send(get_multiple_projects(&client, &["kontraption"]));
+        assert_send(versions);
+        assert_send(async {
+            let versions = get_versions(&client, "kontraption", &v_query).await?;
+
+            resolve_dependencies(
+                &client,
+                &versions[0],
+                &v_query,
+                &ResolveOptions::default(),
+                &VersionStrategy::default(),
+            )
+            .await
+        });
+    }
+
+    #[test]
+    fn errors_are_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<APIError>();
+    }
 }
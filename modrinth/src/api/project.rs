@@ -1,11 +1,13 @@
-use super::{APIError, ENDPOINT};
+use std::fmt::Display;
+
+use super::cache::{get_json, ModrinthClient};
+use super::{APIError, BATCH_SIZE, ENDPOINT};
 use log::info;
-use reqwest::Client;
 
-use crate::types::project::ModrinthProject;
+use crate::types::project::{ModrinthProject, ProjectDependencies};
 use crate::types::query::ProjectQuery;
-use crate::types::result::SearchProjectHit;
 use crate::types::result::SearchProjectResult;
+use crate::types::{serialize_vec, ModrinthProjectMeta};
 
 /// Searches Modrinth projects
 ///
@@ -37,25 +39,29 @@ use crate::types::result::SearchProjectResult;
 ///     assert!(res.is_ok());
 /// }
 /// ```
-pub async fn search_project(
-    client: &Client,
+pub async fn search_project<C>(
+    client: &C,
     params: &ProjectQuery,
-) -> Result<SearchProjectResult, APIError> {
+) -> Result<SearchProjectResult, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
     info!("Searching for project with params: {:?}", params);
-    let resp: SearchProjectResult = client
-        .get(format!("{}/v2/search", ENDPOINT))
-        .query(params)
-        .send()
-        .await
-        .unwrap()
-        .json()
-        .await?;
+    let resp: SearchProjectResult = get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/search", ENDPOINT))
+            .query(params),
+    )
+    .await?;
 
-    assert_eq!(resp.hits.len(), params.limit as usize);
     Ok(resp)
 }
 
-/// Gets a specific project, returned by `search_project`
+#[expect(private_bounds)]
+/// Gets a specific project, either returned by `search_project`
+/// or looked up by its slug or ID
 /// ## Usage
 /// ```
 /// use modrinth::{get_project, search_project, get_client, ProjectQueryBuilder, IndexBy, Facet, Loader, ProjectType};
@@ -73,7 +79,7 @@ pub async fn search_project(
 ///     let res = search_project(&client, &query).await.unwrap();
 ///
 ///     let res = res.hits.first().unwrap();
-///     assert_eq!(res.project_id, "5yJ5IDKm".into()); // https://modrinth.com/mod/kontraption
+///     assert_eq!(res.project_id, "5yJ5IDKm"); // https://modrinth.com/mod/kontraption
 ///     assert_eq!(res.project_type, ProjectType::Mod);
 ///
 ///     let project = get_project(&client, res).await;
@@ -82,22 +88,123 @@ pub async fn search_project(
 ///
 ///     let project = project.unwrap();
 ///
-///     assert_eq!(project.id, "5yJ5IDKm".into());
+///     assert_eq!(project.id, "5yJ5IDKm");
 ///     assert_eq!(project.project_type, ProjectType::Mod);
+///
+///     let by_slug = get_project(&client, "kontraption").await.unwrap();
+///     assert_eq!(by_slug.id, project.id);
 /// }
 /// ```
-pub async fn get_project(
-    client: &Client,
-    project: &SearchProjectHit,
-) -> Result<ModrinthProject, APIError> {
-    info!("Getting project information for {}", project.title);
-    Ok(client
-        .get(format!("{}/v2/project/{}", ENDPOINT, project.project_id))
-        .send()
-        .await
-        .unwrap()
-        .json()
-        .await?)
+pub async fn get_project<C, M>(client: &C, project: &M) -> Result<ModrinthProject, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta + ?Sized,
+    <M as ModrinthProjectMeta>::Id: Display,
+{
+    let project_id = project.project_id().ok_or(APIError::MissingId("project"))?;
+
+    info!("Getting project information for {}", project_id);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/project/{}", ENDPOINT, project_id)),
+    )
+    .await
+}
+
+#[expect(private_bounds)]
+/// Gets several projects at once, each identified by a search hit,
+/// a slug or an ID. Projects are requested in batches, so this costs
+/// one request per hundred projects instead of one per project.
+///
+/// Projects that Modrinth can't find are left out of the result
+/// ## Usage
+/// ```
+/// use modrinth::{get_multiple_projects, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let projects = get_multiple_projects(&client, &["kontraption", "sodium"]).await;
+///
+///     assert!(projects.is_ok_and(|projects| projects.len() == 2));
+/// }
+/// ```
+pub async fn get_multiple_projects<C, M>(
+    client: &C,
+    projects: &[M],
+) -> Result<Vec<ModrinthProject>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta,
+    <M as ModrinthProjectMeta>::Id: Display,
+{
+    let ids = projects
+        .iter()
+        .map(|project| {
+            project
+                .project_id()
+                .map(|id| id.to_string())
+                .ok_or(APIError::MissingId("project"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut resp = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(BATCH_SIZE) {
+        info!("Getting project information for {} projects", chunk.len());
+        let batch: Vec<ModrinthProject> = get_json(
+            client,
+            client
+                .http()
+                .get(format!("{}/v2/projects", ENDPOINT))
+                .query(&[("ids", serialize_vec(&chunk.to_vec()))]),
+        )
+        .await?;
+
+        resp.extend(batch);
+    }
+
+    Ok(resp)
+}
+
+#[expect(private_bounds)]
+/// Gets every project and version that the versions of `project` depend
+/// on, all in one request
+/// ## Usage
+/// ```
+/// use modrinth::{get_project_dependencies, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let dependencies = get_project_dependencies(&client, "botaniacombat").await;
+///
+///     assert!(dependencies.is_ok_and(|dependencies| !dependencies.projects.is_empty()));
+/// }
+/// ```
+pub async fn get_project_dependencies<C, M>(
+    client: &C,
+    project: &M,
+) -> Result<ProjectDependencies, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta + ?Sized,
+    <M as ModrinthProjectMeta>::Id: Display,
+{
+    let project_id = project.project_id().ok_or(APIError::MissingId("project"))?;
+
+    info!("Getting dependencies of project {}", project_id);
+    get_json(
+        client,
+        client.http().get(format!(
+            "{}/v2/project/{}/dependencies",
+            ENDPOINT, project_id
+        )),
+    )
+    .await
 }
 
 #[cfg(test)]
@@ -144,7 +251,7 @@ mod test {
         let res = search_project(&client, &query).await.unwrap();
 
         let res = res.hits.first().unwrap();
-        assert_eq!(res.project_id, "5yJ5IDKm".into()); // https://modrinth.com/mod/kontraption
+        assert_eq!(res.project_id, "5yJ5IDKm"); // https://modrinth.com/mod/kontraption
         assert_eq!(res.project_type, ProjectType::Mod);
 
         let project = get_project(&client, res).await;
@@ -153,7 +260,46 @@ mod test {
 
         let project = project.unwrap();
 
-        assert_eq!(project.id, "5yJ5IDKm".into());
+        assert_eq!(project.id, "5yJ5IDKm");
         assert_eq!(project.project_type, ProjectType::Mod);
     }
+
+    #[tokio::test]
+    async fn check_get_project_by_slug() {
+        let client = get_client().await.unwrap();
+
+        let project = get_project(&client, "kontraption").await;
+
+        assert!(project.is_ok_and(|project| project.id == "5yJ5IDKm"));
+    }
+
+    #[tokio::test]
+    async fn check_get_multiple_projects() {
+        let client = get_client().await.unwrap();
+
+        let projects = get_multiple_projects(&client, &["kontraption", "5yJ5IDKm", "sodium"])
+            .await
+            .unwrap();
+
+        assert!(projects.iter().any(|project| project.slug == "sodium"));
+        assert!(projects.iter().any(|project| project.id == "5yJ5IDKm"));
+    }
+
+    #[tokio::test]
+    async fn check_get_project_dependencies() {
+        let client = get_client().await.unwrap();
+
+        let query = ProjectQueryBuilder::new()
+            .query("BotaniaCombat")
+            .limit(1)
+            .index_by(IndexBy::Relevance)
+            .build();
+
+        let res = search_project(&client, &query).await.unwrap();
+        let project = res.hits.first().unwrap();
+
+        let dependencies = get_project_dependencies(&client, project).await;
+
+        assert!(dependencies.is_ok_and(|dependencies| !dependencies.projects.is_empty()));
+    }
 }
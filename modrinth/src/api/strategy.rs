@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::types::version::VersionType;
+use crate::ModrinthProjectVersion;
+
+#[derive(Debug, Error)]
+#[error("no acceptable version for project {project}: {reason}")]
+/// Returned by a `VersionSelector` when none of the
+/// versions it was given can be installed
+pub struct NoAcceptableVersion {
+    /// The ID of the project a version was picked for
+    pub project: Arc<str>,
+    /// Why none of the versions were picked
+    pub reason: String,
+}
+
+impl NoAcceptableVersion {
+    pub fn new<R: ToString>(project: &str, reason: R) -> Self {
+        Self {
+            project: project.into(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Decides which version of a project `resolve_dependencies` installs.
+///
+/// Implemented by every `VersionStrategy`, and by any function taking
+/// the project ID and its candidate versions
+/// ## Usage
+/// ```
+/// use modrinth::{NoAcceptableVersion, ModrinthProjectVersion, VersionSelector};
+///
+/// let first = |project: &str, versions: Vec<ModrinthProjectVersion>| {
+///     versions
+///         .into_iter()
+///         .next()
+///         .ok_or_else(|| NoAcceptableVersion::new(project, "no versions listed"))
+/// };
+///
+/// assert!(first.select("5yJ5IDKm", vec![]).is_err());
+/// ```
+pub trait VersionSelector {
+    fn select(
+        &self,
+        project: &str,
+        versions: Vec<ModrinthProjectVersion>,
+    ) -> Result<ModrinthProjectVersion, NoAcceptableVersion>;
+}
+
+impl<F> VersionSelector for F
+where
+    F: Fn(&str, Vec<ModrinthProjectVersion>) -> Result<ModrinthProjectVersion, NoAcceptableVersion>,
+{
+    fn select(
+        &self,
+        project: &str,
+        versions: Vec<ModrinthProjectVersion>,
+    ) -> Result<ModrinthProjectVersion, NoAcceptableVersion> {
+        self(project, versions)
+    }
+}
+
+#[derive(Debug, Default)]
+/// Built-in ways of picking a version among a list
+pub enum VersionStrategy {
+    /// The most recently published release. Betas and alphas are never picked
+    NewestRelease,
+    /// The most recently published featured version, falling
+    /// back to `PreferRelease` when none are featured
+    PreferFeatured,
+    /// The most recently published release, falling back to the most
+    /// recently published beta. Alphas are never picked
+    #[default]
+    PreferRelease,
+    /// The most downloaded release or beta. Alphas are never picked
+    MostDownloads,
+    /// Versions pinned by project ID, as either a version ID or a version
+    /// number. Projects without a pin are resolved using `fallback`
+    Pinned {
+        pins: HashMap<Arc<str>, Arc<str>>,
+        fallback: Box<VersionStrategy>,
+    },
+}
+
+impl VersionSelector for VersionStrategy {
+    fn select(
+        &self,
+        project: &str,
+        versions: Vec<ModrinthProjectVersion>,
+    ) -> Result<ModrinthProjectVersion, NoAcceptableVersion> {
+        if versions.is_empty() {
+            return Err(NoAcceptableVersion::new(
+                project,
+                "no versions match the query",
+            ));
+        }
+
+        match self {
+            Self::NewestRelease => newest(versions, |version| {
+                version.version_type == VersionType::Release
+            })
+            .ok_or_else(|| NoAcceptableVersion::new(project, "no release versions available")),
+            Self::PreferFeatured => {
+                if !versions.iter().any(|version| version.featured) {
+                    return Self::PreferRelease.select(project, versions);
+                }
+
+                newest(versions, |version| version.featured)
+                    .ok_or_else(|| NoAcceptableVersion::new(project, "no versions available"))
+            }
+            Self::PreferRelease => {
+                let has_release = versions
+                    .iter()
+                    .any(|version| version.version_type == VersionType::Release);
+                let wanted = if has_release {
+                    VersionType::Release
+                } else {
+                    VersionType::Beta
+                };
+
+                newest(versions, |version| version.version_type == wanted).ok_or_else(|| {
+                    NoAcceptableVersion::new(project, "only alpha versions available")
+                })
+            }
+            Self::MostDownloads => versions
+                .into_iter()
+                .filter(|version| version.version_type != VersionType::Alpha)
+                .max_by_key(|version| version.downloads)
+                .ok_or_else(|| NoAcceptableVersion::new(project, "only alpha versions available")),
+            Self::Pinned { pins, fallback } => match pins.get(project) {
+                Some(pin) => versions
+                    .into_iter()
+                    .find(|version| *version.id == **pin || version.version_number == *pin)
+                    .ok_or_else(|| {
+                        NoAcceptableVersion::new(
+                            project,
+                            format!("pinned version {} is not available", pin),
+                        )
+                    }),
+                None => fallback.select(project, versions),
+            },
+        }
+    }
+}
+
+/// The most recently published version among the ones matching `filter`
+fn newest<F>(versions: Vec<ModrinthProjectVersion>, filter: F) -> Option<ModrinthProjectVersion>
+where
+    F: Fn(&ModrinthProjectVersion) -> bool,
+{
+    versions
+        .into_iter()
+        .filter(filter)
+        .max_by(|a, b| a.date_published.cmp(&b.date_published))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_util;
+    use crate::VersionId;
+
+    fn v(id: &str) -> VersionId {
+        id.parse().unwrap()
+    }
+
+    fn version(
+        id: &str,
+        version_type: VersionType,
+        featured: bool,
+        downloads: usize,
+        date_published: &str,
+    ) -> ModrinthProjectVersion {
+        ModrinthProjectVersion {
+            version_type,
+            featured,
+            downloads,
+            date_published: date_published.parse().unwrap(),
+            ..test_util::version(id, "project", Vec::new())
+        }
+    }
+
+    fn versions() -> Vec<ModrinthProjectVersion> {
+        vec![
+            version("1", VersionType::Release, true, 500, "2023-01-01T00:00:00Z"),
+            version(
+                "2",
+                VersionType::Release,
+                false,
+                100,
+                "2023-02-01T00:00:00Z",
+            ),
+            version("3", VersionType::Beta, false, 50, "2023-03-01T00:00:00Z"),
+            version("4", VersionType::Alpha, false, 10, "2023-04-01T00:00:00Z"),
+        ]
+    }
+
+    fn select(
+        strategy: VersionStrategy,
+        versions: Vec<ModrinthProjectVersion>,
+    ) -> Option<VersionId> {
+        strategy
+            .select("project", versions)
+            .ok()
+            .map(|version| version.id)
+    }
+
+    #[test]
+    fn newest_release() {
+        assert_eq!(
+            select(VersionStrategy::NewestRelease, versions()),
+            Some(v("2"))
+        );
+
+        let unreleased = versions().into_iter().skip(2).collect();
+        assert_eq!(select(VersionStrategy::NewestRelease, unreleased), None);
+    }
+
+    #[test]
+    fn prefer_featured() {
+        assert_eq!(
+            select(VersionStrategy::PreferFeatured, versions()),
+            Some(v("1"))
+        );
+
+        let unfeatured = versions().into_iter().skip(1).collect();
+        assert_eq!(
+            select(VersionStrategy::PreferFeatured, unfeatured),
+            Some(v("2"))
+        );
+
+        let alphas = versions().into_iter().skip(3).collect();
+        assert_eq!(select(VersionStrategy::PreferFeatured, alphas), None);
+    }
+
+    #[test]
+    fn prefer_release() {
+        assert_eq!(
+            select(VersionStrategy::PreferRelease, versions()),
+            Some(v("2"))
+        );
+
+        let betas = versions().into_iter().skip(2).collect();
+        assert_eq!(select(VersionStrategy::PreferRelease, betas), Some(v("3")));
+
+        let alphas = versions().into_iter().skip(3).collect();
+        assert_eq!(select(VersionStrategy::PreferRelease, alphas), None);
+    }
+
+    #[test]
+    fn most_downloads() {
+        assert_eq!(
+            select(VersionStrategy::MostDownloads, versions()),
+            Some(v("1"))
+        );
+
+        let unreleased = versions().into_iter().skip(2).collect();
+        assert_eq!(
+            select(VersionStrategy::MostDownloads, unreleased),
+            Some(v("3"))
+        );
+
+        let alphas = versions().into_iter().skip(3).collect();
+        assert_eq!(select(VersionStrategy::MostDownloads, alphas), None);
+    }
+
+    #[test]
+    fn pinned() {
+        let strategy = |pin: &str| VersionStrategy::Pinned {
+            pins: HashMap::from([("project".into(), pin.into())]),
+            fallback: Box::new(VersionStrategy::NewestRelease),
+        };
+
+        assert_eq!(select(strategy("3"), versions()), Some(v("3")));
+        assert_eq!(select(strategy("4.0.0"), versions()), Some(v("4")));
+        assert_eq!(select(strategy("5"), versions()), None);
+
+        let unpinned = VersionStrategy::Pinned {
+            pins: HashMap::new(),
+            fallback: Box::new(VersionStrategy::NewestRelease),
+        };
+        assert_eq!(select(unpinned, versions()), Some(v("2")));
+    }
+
+    #[test]
+    fn no_versions() {
+        assert_eq!(select(VersionStrategy::MostDownloads, Vec::new()), None);
+    }
+}
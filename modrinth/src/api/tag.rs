@@ -0,0 +1,158 @@
+use super::cache::{get_json, ModrinthClient};
+use super::{APIError, ENDPOINT};
+use log::info;
+
+use crate::types::tag::{CategoryTag, GameVersionTag, LicenseTag, LoaderTag};
+
+/// Lists the categories projects can be listed under
+/// ## Usage
+/// ```
+/// use modrinth::{get_categories, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let categories = get_categories(&client).await;
+///
+///     assert!(categories.is_ok_and(|categories| !categories.is_empty()));
+/// }
+/// ```
+pub async fn get_categories<C>(client: &C) -> Result<Vec<CategoryTag>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    info!("Getting category tags");
+    get_json(
+        client,
+        client.http().get(format!("{}/v2/tag/category", ENDPOINT)),
+    )
+    .await
+}
+
+/// Lists the loaders projects can be loaded by
+/// ## Usage
+/// ```
+/// use modrinth::{get_loaders, get_client, Loader};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let loaders = get_loaders(&client).await.unwrap();
+///
+///     assert!(loaders.iter().any(|loader| loader.name == Loader::Fabric));
+/// }
+/// ```
+pub async fn get_loaders<C>(client: &C) -> Result<Vec<LoaderTag>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    info!("Getting loader tags");
+    get_json(
+        client,
+        client.http().get(format!("{}/v2/tag/loader", ENDPOINT)),
+    )
+    .await
+}
+
+/// Lists every version of Minecraft
+/// ## Usage
+/// ```
+/// use modrinth::{get_game_versions, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let versions = get_game_versions(&client).await.unwrap();
+///
+///     assert!(versions.iter().any(|version| &*version.version == "1.20.1"));
+/// }
+/// ```
+pub async fn get_game_versions<C>(client: &C) -> Result<Vec<GameVersionTag>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    info!("Getting game version tags");
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/tag/game_version", ENDPOINT)),
+    )
+    .await
+}
+
+/// Lists the licenses projects can be licensed under
+/// ## Usage
+/// ```
+/// use modrinth::{get_licenses, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let licenses = get_licenses(&client).await;
+///
+///     assert!(licenses.is_ok_and(|licenses| !licenses.is_empty()));
+/// }
+/// ```
+pub async fn get_licenses<C>(client: &C) -> Result<Vec<LicenseTag>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    info!("Getting license tags");
+    get_json(
+        client,
+        client.http().get(format!("{}/v2/tag/license", ENDPOINT)),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::get_client;
+    use crate::types::tag::GameVersionType;
+    use crate::types::Loader;
+
+    #[tokio::test]
+    async fn check_get_categories() {
+        let client = get_client().await.unwrap();
+
+        let categories = get_categories(&client).await.unwrap();
+
+        assert!(categories
+            .iter()
+            .any(|category| &*category.name == "adventure"));
+    }
+
+    #[tokio::test]
+    async fn check_get_loaders() {
+        let client = get_client().await.unwrap();
+
+        let loaders = get_loaders(&client).await.unwrap();
+
+        assert!(loaders.iter().any(|loader| loader.name == Loader::Forge));
+    }
+
+    #[tokio::test]
+    async fn check_get_game_versions() {
+        let client = get_client().await.unwrap();
+
+        let versions = get_game_versions(&client).await.unwrap();
+
+        assert!(versions.iter().any(|version| &*version.version == "1.20.1"
+            && version.version_type == GameVersionType::Release));
+    }
+
+    #[tokio::test]
+    async fn check_get_licenses() {
+        let client = get_client().await.unwrap();
+
+        let licenses = get_licenses(&client).await.unwrap();
+
+        assert!(licenses.iter().any(|license| &*license.short == "mit"));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::types::version::{
+    ModrinthProjectVersion, VersionDependency, VersionFile, VersionFileHashes, VersionType,
+};
+
+/// A release of `project_id` named and numbered after `id`,
+/// with a single file, published on 2023-01-01
+pub(crate) fn version(
+    id: &str,
+    project_id: &str,
+    dependencies: Vec<VersionDependency>,
+) -> ModrinthProjectVersion {
+    ModrinthProjectVersion {
+        name: id.into(),
+        version_number: format!("{}.0.0", id).into(),
+        changelog: None,
+        dependencies: Some(dependencies),
+        game_versions: vec!["1.20.1".into()],
+        version_type: VersionType::Release,
+        loaders: None,
+        featured: false,
+        id: id.parse().unwrap(),
+        project_id: project_id.parse().unwrap(),
+        author_id: "author".parse().unwrap(),
+        date_published: "2023-01-01T00:00:00Z".parse().unwrap(),
+        downloads: 0,
+        files: vec![VersionFile {
+            hashes: VersionFileHashes {
+                sha512: "abc".into(),
+                sha1: "def".into(),
+            },
+            url: format!("https://cdn.modrinth.com/data/{}/{}.jar", project_id, id).into(),
+            filename: format!("{}.jar", id).into(),
+            primary: true,
+            size: 1024,
+            file_type: None,
+        }],
+    }
+}
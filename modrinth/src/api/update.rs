@@ -0,0 +1,134 @@
+use std::cmp::Reverse;
+use std::fmt::Display;
+
+use super::cache::ModrinthClient;
+use super::user::get_followed_projects;
+use super::APIError;
+use crate::types::project::ModrinthProject;
+use crate::types::query::VersionQuery;
+use crate::types::DateTime;
+use crate::version::get_versions;
+use crate::ModrinthProjectVersion;
+use log::{debug, info};
+
+#[derive(Debug)]
+/// The versions of a project published after a given time
+pub struct ProjectUpdates {
+    /// The updated project
+    pub project: ModrinthProject,
+    /// The new versions, newest first
+    pub versions: Vec<ModrinthProjectVersion>,
+}
+
+/// Lists the versions of `project` published after `since`, newest first.
+///
+/// Projects that haven't been updated since then are skipped
+/// without asking Modrinth for their versions
+pub async fn get_new_versions<C>(
+    client: &C,
+    project: &ModrinthProject,
+    since: &DateTime,
+    params: &VersionQuery,
+) -> Result<Vec<ModrinthProjectVersion>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    if project.updated <= *since {
+        debug!("{} hasn't been updated since {}", project.title, since);
+        return Ok(Vec::new());
+    }
+
+    let mut versions = get_versions(client, project, params)
+        .await?
+        .into_iter()
+        .filter(|version| version.date_published > *since)
+        .collect::<Vec<_>>();
+    versions.sort_by_key(|version| Reverse(version.date_published));
+
+    Ok(versions)
+}
+
+/// Lists the new versions of every project `user` follows, skipping
+/// projects without any. See `get_followed_projects` for `token`
+/// ## Usage
+/// ```no_run
+/// use modrinth::{get_followed_updates, get_client, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///     let token = std::env::var("MODRINTH_TOKEN").unwrap();
+///
+///     let since = "2024-01-01T00:00:00Z".parse().unwrap();
+///     let v_query = VersionQueryBuilder::new().include_changelog(false).build();
+///
+///     let updates = get_followed_updates(&client, "jellysquid3", &token, &since, &v_query)
+///         .await
+///         .unwrap();
+///
+///     for update in updates {
+///         println!(
+///             "{}: {} new versions, latest is {}",
+///             update.project.title,
+///             update.versions.len(),
+///             update.versions[0].version_number
+///         );
+///     }
+/// }
+/// ```
+pub async fn get_followed_updates<C, U>(
+    client: &C,
+    user: &U,
+    token: &str,
+    since: &DateTime,
+    params: &VersionQuery,
+) -> Result<Vec<ProjectUpdates>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    U: Display + ?Sized,
+{
+    let followed = get_followed_projects(client, user, token).await?;
+    info!(
+        "Checking {} followed projects for updates since {}",
+        followed.len(),
+        since
+    );
+
+    let mut updates = Vec::new();
+    for project in followed {
+        let versions = get_new_versions(client, &project, since, params).await?;
+
+        if !versions.is_empty() {
+            updates.push(ProjectUpdates { project, versions });
+        }
+    }
+
+    Ok(updates)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{get_client, get_project, VersionQueryBuilder};
+
+    #[tokio::test]
+    async fn check_get_new_versions() {
+        let client = get_client().await.unwrap();
+
+        let project = get_project(&client, "kontraption").await.unwrap();
+        let v_query = VersionQueryBuilder::new().include_changelog(false).build();
+
+        let all = get_new_versions(&client, &project, &DateTime::UNIX_EPOCH, &v_query)
+            .await
+            .unwrap();
+        let none = get_new_versions(&client, &project, &project.updated, &v_query)
+            .await
+            .unwrap();
+
+        assert!(!all.is_empty());
+        assert!(all
+            .windows(2)
+            .all(|pair| pair[0].date_published >= pair[1].date_published));
+        assert!(none.is_empty());
+    }
+}
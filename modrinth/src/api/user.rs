@@ -0,0 +1,281 @@
+use std::fmt::Display;
+
+use super::cache::{get_json, ModrinthClient};
+use super::{APIError, ENDPOINT};
+use log::info;
+use reqwest::header::AUTHORIZATION;
+
+use crate::types::notification::Notification;
+use crate::types::project::ModrinthProject;
+use crate::types::user::{TeamMember, User};
+use crate::types::ModrinthProjectMeta;
+use crate::ModrinthProjectVersion;
+
+#[expect(private_bounds)]
+/// Lists the members of the team owning `project`
+/// ## Usage
+/// ```
+/// use modrinth::{get_project_members, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let members = get_project_members(&client, "kontraption").await;
+///
+///     assert!(members.is_ok_and(|members| !members.is_empty()));
+/// }
+/// ```
+pub async fn get_project_members<C, M>(client: &C, project: &M) -> Result<Vec<TeamMember>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta + ?Sized,
+    <M as ModrinthProjectMeta>::Id: Display,
+{
+    let project_id = project.project_id().ok_or(APIError::MissingId("project"))?;
+
+    info!("Getting team members of project {}", project_id);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/project/{}/members", ENDPOINT, project_id)),
+    )
+    .await
+}
+
+/// Lists the members of the team with the ID `team`,
+/// such as the one in `ModrinthProject.team`
+pub async fn get_team_members<C, T>(client: &C, team: &T) -> Result<Vec<TeamMember>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    T: Display + ?Sized,
+{
+    info!("Getting members of team {}", team);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/team/{}/members", ENDPOINT, team)),
+    )
+    .await
+}
+
+/// Lists the members of the team that owns `project`
+/// ## Usage
+/// ```
+/// use modrinth::{get_project, get_project_team, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let project = get_project(&client, "kontraption").await.unwrap();
+///     let members = get_project_team(&client, &project).await.unwrap();
+///
+///     assert!(members.iter().all(|member| member.team_id == project.team));
+/// }
+/// ```
+pub async fn get_project_team<C>(
+    client: &C,
+    project: &ModrinthProject,
+) -> Result<Vec<TeamMember>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    get_team_members(client, &*project.team).await
+}
+
+/// Gets a user by their username or ID
+/// ## Usage
+/// ```
+/// use modrinth::{get_user, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let user = get_user(&client, "jellysquid3").await;
+///
+///     assert!(user.is_ok_and(|user| &*user.username == "jellysquid3"));
+/// }
+/// ```
+pub async fn get_user<C, U>(client: &C, user: &U) -> Result<User, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    U: Display + ?Sized,
+{
+    info!("Getting user {}", user);
+    get_json(
+        client,
+        client.http().get(format!("{}/v2/user/{}", ENDPOINT, user)),
+    )
+    .await
+}
+
+/// Gets the user who published `version`
+/// ## Usage
+/// ```
+/// use modrinth::{get_version_author, get_versions, get_client, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let v_query = VersionQueryBuilder::new().build();
+///     let versions = get_versions(&client, "kontraption", &v_query).await.unwrap();
+///     let version = versions.first().unwrap();
+///
+///     let author = get_version_author(&client, version).await;
+///
+///     assert!(author.is_ok_and(|author| author.id == version.author_id));
+/// }
+/// ```
+pub async fn get_version_author<C>(
+    client: &C,
+    version: &ModrinthProjectVersion,
+) -> Result<User, APIError>
+where
+    C: ModrinthClient + ?Sized,
+{
+    get_user(client, &*version.author_id).await
+}
+
+/// Lists the projects of a user, identified by their username or ID
+/// ## Usage
+/// ```
+/// use modrinth::{get_user_projects, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let projects = get_user_projects(&client, "jellysquid3").await;
+///
+///     assert!(projects.is_ok_and(|projects| !projects.is_empty()));
+/// }
+/// ```
+pub async fn get_user_projects<C, U>(client: &C, user: &U) -> Result<Vec<ModrinthProject>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    U: Display + ?Sized,
+{
+    info!("Getting projects of user {}", user);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/user/{}/projects", ENDPOINT, user)),
+    )
+    .await
+}
+
+/// Lists the projects a user, identified by their username or ID, follows.
+///
+/// Modrinth only shows this to the user themselves, so `token` has to be
+/// one of their [personal access tokens](https://modrinth.com/settings/pats)
+/// with the `USER_READ` scope
+/// ## Usage
+/// ```no_run
+/// use modrinth::{get_followed_projects, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///     let token = std::env::var("MODRINTH_TOKEN").unwrap();
+///
+///     let projects = get_followed_projects(&client, "jellysquid3", &token).await;
+///
+///     assert!(projects.is_ok());
+/// }
+/// ```
+pub async fn get_followed_projects<C, U>(
+    client: &C,
+    user: &U,
+    token: &str,
+) -> Result<Vec<ModrinthProject>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    U: Display + ?Sized,
+{
+    info!("Getting projects followed by user {}", user);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/user/{}/follows", ENDPOINT, user))
+            .header(AUTHORIZATION, token),
+    )
+    .await
+}
+
+/// Lists the notifications of a user, identified by their username or ID.
+///
+/// Modrinth only shows this to the user themselves, so `token` has to be
+/// one of their [personal access tokens](https://modrinth.com/settings/pats)
+/// with the `NOTIFICATION_READ` scope
+/// ## Usage
+/// ```no_run
+/// use modrinth::{get_notifications, get_client};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///     let token = std::env::var("MODRINTH_TOKEN").unwrap();
+///
+///     let notifications = get_notifications(&client, "jellysquid3", &token).await;
+///
+///     assert!(notifications.is_ok());
+/// }
+/// ```
+pub async fn get_notifications<C, U>(
+    client: &C,
+    user: &U,
+    token: &str,
+) -> Result<Vec<Notification>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    U: Display + ?Sized,
+{
+    info!("Getting notifications of user {}", user);
+    get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/user/{}/notifications", ENDPOINT, user))
+            .header(AUTHORIZATION, token),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{get_client, get_project};
+
+    #[tokio::test]
+    async fn check_get_project_members() {
+        let client = get_client().await.unwrap();
+
+        let project = get_project(&client, "kontraption").await.unwrap();
+        let by_project = get_project_members(&client, &project).await.unwrap();
+        let by_team = get_project_team(&client, &project).await.unwrap();
+
+        assert!(!by_project.is_empty());
+        assert_eq!(by_project.len(), by_team.len());
+    }
+
+    #[tokio::test]
+    async fn check_get_user() {
+        let client = get_client().await.unwrap();
+
+        let members = get_project_members(&client, "kontraption").await.unwrap();
+        let member = members.first().unwrap();
+
+        let user = get_user(&client, &*member.user.id).await.unwrap();
+        let projects = get_user_projects(&client, &*user.username).await.unwrap();
+
+        assert_eq!(user.username, member.user.username);
+        assert!(projects.iter().any(|project| project.id == "5yJ5IDKm"));
+    }
+}
@@ -1,14 +1,14 @@
 use std::fmt::{Debug, Display};
 
-use super::{APIError, ENDPOINT};
+use super::cache::{get_json, ModrinthClient};
+use super::{APIError, BATCH_SIZE, ENDPOINT};
 use log::info;
-use reqwest::Client;
 
 use crate::types::query::VersionQuery;
 use crate::types::version::ModrinthProjectVersion;
-use crate::types::ModrinthProjectMeta;
+use crate::types::{serialize_vec, ModrinthProjectMeta};
 
-#[allow(private_bounds)]
+#[expect(private_bounds)]
 /// Lists versions of `project`
 /// ## Usage
 /// ```
@@ -42,58 +42,129 @@ use crate::types::ModrinthProjectMeta;
 ///     assert!(!version.unwrap().is_empty());
 /// }
 /// ```
-pub async fn get_versions<M>(
-    client: &Client,
+pub async fn get_versions<C, M>(
+    client: &C,
     project: &M,
     params: &VersionQuery,
 ) -> Result<Vec<ModrinthProjectVersion>, APIError>
 where
-    M: ModrinthProjectMeta,
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta + ?Sized,
     <M as ModrinthProjectMeta>::Id: Display,
 {
     info!("Searching for versions with params: {:?}", params);
 
-    let resp: Vec<ModrinthProjectVersion> = client
-        // TODO: ADD ERROR
-        .get(format!(
-            "{}/v2/project/{}/version",
-            ENDPOINT,
-            project.project_id().unwrap()
-        ))
-        .query(params)
-        .send()
-        .await
-        .unwrap()
-        // .text()
-        .json()
-        .await?;
+    let project_id = project.project_id().ok_or(APIError::MissingId("project"))?;
+    let resp: Vec<ModrinthProjectVersion> = get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/project/{}/version", ENDPOINT, project_id))
+            .query(params),
+    )
+    .await?;
 
     Ok(resp)
 }
 
-pub(crate) async fn get_version<M>(
-    client: &Client,
-    project: &M,
-) -> Result<ModrinthProjectVersion, APIError>
+#[expect(private_bounds)]
+/// Gets a specific version, identified by either a dependency
+/// or its ID
+/// ## Usage
+/// ```
+/// use modrinth::{get_version, get_versions, get_client, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let v_query = VersionQueryBuilder::new().build();
+///     let versions = get_versions(&client, "kontraption", &v_query).await.unwrap();
+///     let latest = versions.first().unwrap();
+///
+///     let version = get_version(&client, &latest.id).await;
+///
+///     assert!(version.is_ok_and(|version| version.project_id == "5yJ5IDKm"));
+/// }
+/// ```
+pub async fn get_version<C, M>(client: &C, project: &M) -> Result<ModrinthProjectVersion, APIError>
 where
-    M: ModrinthProjectMeta,
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta + ?Sized,
     <M as ModrinthProjectMeta>::Id: Display + Debug,
 {
-    info!("Searching for version: {:?}", project.version_id().unwrap());
-
-    let resp: ModrinthProjectVersion = client
-        // TODO: ADD ERROR
-        .get(format!(
-            "{}/v2/version/{}",
-            ENDPOINT,
-            project.version_id().unwrap()
-        ))
-        .send()
-        .await
-        .unwrap()
-        .json()
+    let version_id = project.version_id().ok_or(APIError::MissingId("version"))?;
+    info!("Searching for version: {:?}", version_id);
+
+    let resp: ModrinthProjectVersion = get_json(
+        client,
+        client
+            .http()
+            .get(format!("{}/v2/version/{}", ENDPOINT, version_id)),
+    )
+    .await?;
+
+    Ok(resp)
+}
+
+#[expect(private_bounds)]
+/// Gets several versions at once, each identified by either a
+/// dependency or an ID. Versions are requested in batches, so
+/// this costs one request per hundred versions instead of one
+/// per version.
+///
+/// Versions that Modrinth can't find are left out of the result
+/// ## Usage
+/// ```
+/// use modrinth::{get_multiple_versions, get_versions, get_client, VersionQueryBuilder};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let v_query = VersionQueryBuilder::new().build();
+///     let versions = get_versions(&client, "kontraption", &v_query).await.unwrap();
+///     let ids = versions.iter().map(|version| version.id.clone()).collect::<Vec<_>>();
+///
+///     let fetched = get_multiple_versions(&client, &ids).await;
+///
+///     assert!(fetched.is_ok_and(|fetched| fetched.len() == versions.len()));
+/// }
+/// ```
+pub async fn get_multiple_versions<C, M>(
+    client: &C,
+    versions: &[M],
+) -> Result<Vec<ModrinthProjectVersion>, APIError>
+where
+    C: ModrinthClient + ?Sized,
+    M: ModrinthProjectMeta,
+    <M as ModrinthProjectMeta>::Id: Display,
+{
+    let ids = versions
+        .iter()
+        .map(|version| {
+            version
+                .version_id()
+                .map(|id| id.to_string())
+                .ok_or(APIError::MissingId("version"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut resp = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(BATCH_SIZE) {
+        info!("Searching for {} versions", chunk.len());
+        let batch: Vec<ModrinthProjectVersion> = get_json(
+            client,
+            client
+                .http()
+                .get(format!("{}/v2/versions", ENDPOINT))
+                .query(&[("ids", serialize_vec(&chunk.to_vec()))]),
+        )
         .await?;
 
+        resp.extend(batch);
+    }
+
     Ok(resp)
 }
 
@@ -128,4 +199,29 @@ mod test {
         assert!(version.is_ok());
         assert!(!version.unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn check_get_multiple_versions() {
+        let client = get_client().await.unwrap();
+
+        let query = ProjectQueryBuilder::new()
+            .query("kontraption")
+            .limit(1)
+            .index_by(IndexBy::Relevance)
+            .build();
+
+        let res = search_project(&client, &query).await.unwrap();
+        let project = res.hits.first().unwrap();
+
+        let v_query = VersionQueryBuilder::new().build();
+        let versions = get_versions(&client, &project, &v_query).await.unwrap();
+        let ids = versions
+            .iter()
+            .map(|version| version.id.clone())
+            .collect::<Vec<_>>();
+
+        let fetched = get_multiple_versions(&client, &ids).await;
+
+        assert!(fetched.is_ok_and(|fetched| fetched.len() == versions.len()));
+    }
 }
@@ -1,15 +1,22 @@
 #[cfg(feature = "api")]
 pub mod api;
+#[cfg(feature = "mrpack")]
+pub mod mrpack;
 #[cfg(feature = "types")]
 pub mod types;
 
 #[cfg(feature = "api")]
 pub use api::*;
+#[cfg(feature = "mrpack")]
+pub use mrpack::{MrpackBuilder, PackError};
 #[cfg(feature = "types")]
 pub use types::project::ModrinthProject;
 #[cfg(feature = "types")]
 pub use types::query::{ProjectQueryBuilder, VersionQueryBuilder};
 #[cfg(feature = "types")]
-pub use types::version::ModrinthProjectVersion;
+pub use types::version::{ModrinthProjectVersion, VersionType};
 #[cfg(feature = "types")]
-pub use types::{Facet, FacetOp, IndexBy, Loader, ProjectType};
+pub use types::{
+    Facet, FacetOp, Facets, IndexBy, Loader, ModRequirement, ProjectId, ProjectType, Slug, TeamId,
+    UserId, VersionId,
+};
@@ -0,0 +1,620 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::types::project::ModrinthProject;
+use crate::types::version::{is_plain_file_name, DependencyType, LockedVersion};
+use crate::types::{Loader, ModRequirement, ProjectId, ProjectType};
+
+/// Where the index is stored in a `.mrpack`
+pub const INDEX_FILE: &str = "modrinth.index.json";
+/// The folder whose contents are copied as-is into the instance
+pub const OVERRIDES_DIR: &str = "overrides";
+
+#[derive(Debug, Error)]
+pub enum PackError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("modpacks can't be loaded by {}", .0.to_string())]
+    UnsupportedLoader(Loader),
+
+    #[error("no minecraft version set for the modpack")]
+    NoGameVersion,
+
+    #[error("project {0} of a locked version wasn't added with `.projects()`")]
+    MissingProject(ProjectId),
+
+    #[error("modpacks can't contain {}s", .0.to_string())]
+    UnsupportedProjectType(ProjectType),
+
+    #[error("refusing to put {0:?} in the pack, it isn't a plain file name")]
+    InvalidFileName(Arc<str>),
+
+    #[error("override path {0:?} has to stay inside of the overrides folder")]
+    InvalidOverridePath(PathBuf),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The `modrinth.index.json` of a `.mrpack`.
+///
+/// *See [Modrinth's documentation](https://support.modrinth.com/en/articles/8802351-modrinth-modpack-format-mrpack)*
+/// *for details on the format*
+pub struct PackIndex {
+    /// The version of the format, always 1
+    pub format_version: u32,
+    /// The game the pack is for, always `minecraft`
+    pub game: Arc<str>,
+    /// The version of the pack itself
+    pub version_id: Arc<str>,
+    /// The name of the pack
+    pub name: Arc<str>,
+    /// A short description of the pack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Arc<str>>,
+    /// The files to download when installing the pack
+    pub files: Vec<PackFile>,
+    /// The Minecraft and loader versions the pack runs on,
+    /// keyed by `minecraft`, `forge`, `neoforge`,
+    /// `fabric-loader` or `quilt-loader`
+    pub dependencies: HashMap<Arc<str>, Arc<str>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A file to download when installing the pack
+pub struct PackFile {
+    /// Where the file goes, relative to the instance folder
+    pub path: Arc<str>,
+    /// Hashes of the file
+    pub hashes: PackFileHashes,
+    /// Which sides need the file. Every side does if left out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<PackEnv>,
+    /// Where the file can be downloaded from
+    pub downloads: Vec<Arc<str>>,
+    /// Size of the file in bytes
+    pub file_size: usize,
+}
+
+impl PackFile {
+    /// Puts the file of `version` in the folder the type of `project` is
+    /// loaded from, needed on the sides `project` supports. Optional
+    /// dependencies are only ever optional
+    pub fn locked(version: &LockedVersion, project: &ModrinthProject) -> Result<Self, PackError> {
+        Self::new(version, &project.into())
+    }
+
+    fn new(version: &LockedVersion, project: &PackProject) -> Result<Self, PackError> {
+        let dir = match project.project_type {
+            ProjectType::Mod => "mods",
+            ProjectType::Resourcepack => "resourcepacks",
+            ProjectType::Shader => "shaderpacks",
+            ProjectType::Modpack => {
+                return Err(PackError::UnsupportedProjectType(ProjectType::Modpack))
+            }
+        };
+
+        if !is_plain_file_name(&version.file.filename) {
+            return Err(PackError::InvalidFileName(version.file.filename.clone()));
+        }
+
+        let optional = version.dependency_type == Some(DependencyType::Optional);
+        let side = |requirement| match PackRequirement::from(requirement) {
+            PackRequirement::Required if optional => PackRequirement::Optional,
+            requirement => requirement,
+        };
+
+        Ok(Self {
+            path: format!("{}/{}", dir, version.file.filename).into(),
+            hashes: PackFileHashes {
+                sha1: version.file.hashes.sha1.clone(),
+                sha512: version.file.hashes.sha512.clone(),
+            },
+            env: Some(PackEnv {
+                client: side(project.client_side),
+                server: side(project.server_side),
+            }),
+            downloads: vec![version.file.url.clone()],
+            file_size: version.file.size,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackFileHashes {
+    /// SHA1 hash of the file
+    pub sha1: Arc<str>,
+    /// SHA512 hash of the file
+    pub sha512: Arc<str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Whether a file is needed on the client and on the server
+pub struct PackEnv {
+    pub client: PackRequirement,
+    pub server: PackRequirement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// Whether one side needs a file of the pack
+pub enum PackRequirement {
+    Required,
+    Optional,
+    Unsupported,
+}
+
+impl From<ModRequirement> for PackRequirement {
+    /// Sides Modrinth doesn't know about get the file, so nothing goes missing
+    fn from(requirement: ModRequirement) -> Self {
+        match requirement {
+            ModRequirement::Required | ModRequirement::Unknown => Self::Required,
+            ModRequirement::Optional => Self::Optional,
+            ModRequirement::Unsupported => Self::Unsupported,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// Builds a `.mrpack` out of resolved versions and local files. The projects
+/// of the versions have to be added too, they tell where each file goes and
+/// which sides need it. Use `.write_to()` to write the pack
+/// ## Usage
+#[cfg_attr(feature = "api", doc = "```")]
+#[cfg_attr(not(feature = "api"), doc = "```ignore")]
+/// use modrinth::{resolve_dependencies, get_client, get_multiple_projects, get_versions, Loader, MrpackBuilder, ResolveOptions, VersionQueryBuilder, VersionStrategy};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = get_client().await.unwrap();
+///
+///     let v_query = VersionQueryBuilder::new()
+///         .versions(vec!["1.20.1"])
+///         .loaders(vec![Loader::Fabric])
+///         .build();
+///
+///     let versions = get_versions(&client, "botaniacombat", &v_query).await.unwrap();
+///     let lock = resolve_dependencies(
+///         &client,
+///         versions.first().unwrap(),
+///         &v_query,
+///         &ResolveOptions::default(),
+///         &VersionStrategy::PreferRelease,
+///     )
+///     .await
+///     .unwrap();
+///
+///     let ids = lock.iter().map(|version| version.project_id.to_string()).collect::<Vec<_>>();
+///     let projects = get_multiple_projects(&client, &ids).await.unwrap();
+///
+///     let pack = MrpackBuilder::new("Botania Combat", "1.0.0")
+///         .minecraft("1.20.1")
+///         .loader(Loader::Fabric, "0.15.11")
+///         .versions(&lock)
+///         .projects(&projects);
+///
+///     let res = pack.write_to(std::env::temp_dir().join("botania-combat.mrpack"));
+///
+///     assert!(res.is_ok());
+/// }
+/// ```
+pub struct MrpackBuilder {
+    name: Arc<str>,
+    version_id: Arc<str>,
+    summary: Option<Arc<str>>,
+    minecraft: Option<Arc<str>>,
+    loader: Option<(Loader, Arc<str>)>,
+    versions: Vec<LockedVersion>,
+    projects: HashMap<ProjectId, PackProject>,
+    files: Vec<PackFile>,
+    overrides: Vec<(PathBuf, PathBuf)>,
+}
+
+#[derive(Debug)]
+/// What the pack needs to know about the project of a locked version
+struct PackProject {
+    project_type: ProjectType,
+    client_side: ModRequirement,
+    server_side: ModRequirement,
+}
+
+impl From<&ModrinthProject> for PackProject {
+    fn from(project: &ModrinthProject) -> Self {
+        Self {
+            project_type: project.project_type,
+            client_side: project.client_side,
+            server_side: project.server_side,
+        }
+    }
+}
+
+impl MrpackBuilder {
+    /// Creates a pack named `name`, at version `version_id`
+    pub fn new<N: Into<Arc<str>>, V: Into<Arc<str>>>(name: N, version_id: V) -> Self {
+        Self {
+            name: name.into(),
+            version_id: version_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// A short description of the pack
+    pub fn summary<S: Into<Arc<str>>>(mut self, summary: S) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// The Minecraft version the pack runs on
+    pub fn minecraft<V: Into<Arc<str>>>(mut self, version: V) -> Self {
+        self.minecraft = Some(version.into());
+        self
+    }
+
+    /// The mod loader the pack runs on. Only Forge, NeoForge,
+    /// Fabric and Quilt can load modpacks
+    pub fn loader<V: Into<Arc<str>>>(mut self, loader: Loader, version: V) -> Self {
+        self.loader = Some((loader, version.into()));
+        self
+    }
+
+    /// Adds every version of a lock list, as returned by `resolve_dependencies`.
+    /// Their projects have to be added with `.projects()`
+    pub fn versions(mut self, versions: &[LockedVersion]) -> Self {
+        self.versions.extend_from_slice(versions);
+        self
+    }
+
+    /// Adds the projects of the versions added with `.versions()`
+    pub fn projects(mut self, projects: &[ModrinthProject]) -> Self {
+        self.projects.extend(
+            projects
+                .iter()
+                .map(|project| (project.id.clone(), project.into())),
+        );
+        self
+    }
+
+    /// Adds a single file to download
+    pub fn file(mut self, file: PackFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Copies `source` into the pack's overrides at `path`, which has
+    /// to be relative and can't go up with `..`. If `source` is a
+    /// folder, all of its contents are copied
+    pub fn overrides<P: Into<PathBuf>, S: Into<PathBuf>>(mut self, path: P, source: S) -> Self {
+        self.overrides.push((path.into(), source.into()));
+        self
+    }
+
+    /// Builds the pack's index
+    pub fn index(&self) -> Result<PackIndex, PackError> {
+        let minecraft = self.minecraft.clone().ok_or(PackError::NoGameVersion)?;
+        let mut dependencies = HashMap::from([("minecraft".into(), minecraft)]);
+
+        if let Some((loader, version)) = self.loader.as_ref() {
+            let key = match loader {
+                Loader::Forge => "forge",
+                Loader::Neoforge => "neoforge",
+                Loader::Fabric => "fabric-loader",
+                Loader::Quilt => "quilt-loader",
+                other => return Err(PackError::UnsupportedLoader(other.clone())),
+            };
+
+            dependencies.insert(key.into(), version.clone());
+        }
+
+        let mut files = self
+            .versions
+            .iter()
+            .map(|version| {
+                let project = self
+                    .projects
+                    .get(&version.project_id)
+                    .ok_or_else(|| PackError::MissingProject(version.project_id.clone()))?;
+
+                PackFile::new(version, project)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        files.extend(self.files.iter().cloned());
+
+        Ok(PackIndex {
+            format_version: 1,
+            game: "minecraft".into(),
+            version_id: self.version_id.clone(),
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            files,
+            dependencies,
+        })
+    }
+
+    /// Writes the pack to `path`
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), PackError> {
+        let index = self.index()?;
+        if let Some((path, _)) = self.overrides.iter().find(|(path, _)| {
+            !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        }) {
+            return Err(PackError::InvalidOverridePath(path.clone()));
+        }
+
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        info!(
+            "Writing modpack {} to {}",
+            self.name,
+            path.as_ref().display()
+        );
+        let mut zip = ZipWriter::new(File::create(path)?);
+
+        zip.start_file(INDEX_FILE, options)?;
+        serde_json::to_writer_pretty(&mut zip, &index)?;
+
+        for (path, source) in self.overrides.iter() {
+            add_override(
+                &mut zip,
+                &Path::new(OVERRIDES_DIR).join(path),
+                source,
+                options,
+            )?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+/// Copies `source` to `path` in `zip`, going through folders recursively
+fn add_override<W>(
+    zip: &mut ZipWriter<W>,
+    path: &Path,
+    source: &Path,
+    options: FileOptions,
+) -> Result<(), PackError>
+where
+    W: Write + io::Seek,
+{
+    // Zip entries always use forward slashes
+    let name = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if source.is_dir() {
+        debug!("Adding override folder {}", name);
+        zip.add_directory(name, options)?;
+
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            add_override(zip, &path.join(entry.file_name()), &entry.path(), options)?;
+        }
+    } else {
+        debug!("Adding override {}", name);
+        zip.start_file(name, options)?;
+        io::copy(&mut File::open(source)?, zip)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::version::{VersionFile, VersionFileHashes};
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn locked(
+        project_id: &str,
+        filename: &str,
+        dependency_type: Option<DependencyType>,
+    ) -> LockedVersion {
+        LockedVersion {
+            project_id: project_id.parse().unwrap(),
+            version_id: "AANobbMI".parse().unwrap(),
+            name: filename.into(),
+            version_number: "1.0.0".into(),
+            dependency_type,
+            file: VersionFile {
+                hashes: VersionFileHashes {
+                    sha512: "abc".into(),
+                    sha1: "def".into(),
+                },
+                url: format!("https://cdn.modrinth.com/data/{}/{}", project_id, filename).into(),
+                filename: filename.into(),
+                primary: true,
+                size: 1024,
+                file_type: None,
+            },
+        }
+    }
+
+    fn project(
+        project_type: ProjectType,
+        client_side: ModRequirement,
+        server_side: ModRequirement,
+    ) -> PackProject {
+        PackProject {
+            project_type,
+            client_side,
+            server_side,
+        }
+    }
+
+    #[test]
+    fn project_files() {
+        let shader = project(
+            ProjectType::Shader,
+            ModRequirement::Required,
+            ModRequirement::Unsupported,
+        );
+        let file = PackFile::new(&locked("5yJ5IDKm", "shader.zip", None), &shader).unwrap();
+        let env = file.env.unwrap();
+
+        assert_eq!(&*file.path, "shaderpacks/shader.zip");
+        assert_eq!(env.client, PackRequirement::Required);
+        assert_eq!(env.server, PackRequirement::Unsupported);
+
+        let library = project(
+            ProjectType::Mod,
+            ModRequirement::Unknown,
+            ModRequirement::Optional,
+        );
+        let optional = locked("5yJ5IDKm", "lib.jar", Some(DependencyType::Optional));
+        let env = PackFile::new(&optional, &library).unwrap().env.unwrap();
+
+        assert_eq!(env.client, PackRequirement::Optional);
+        assert_eq!(env.server, PackRequirement::Optional);
+
+        let modpack = project(
+            ProjectType::Modpack,
+            ModRequirement::Required,
+            ModRequirement::Required,
+        );
+        assert!(matches!(
+            PackFile::new(&locked("5yJ5IDKm", "pack.mrpack", None), &modpack),
+            Err(PackError::UnsupportedProjectType(ProjectType::Modpack))
+        ));
+    }
+
+    #[test]
+    fn unsafe_paths() {
+        let library = project(
+            ProjectType::Mod,
+            ModRequirement::Required,
+            ModRequirement::Required,
+        );
+        for filename in ["../lib.jar", "/etc/passwd", "config/lib.jar", ".."] {
+            assert!(
+                matches!(
+                    PackFile::new(&locked("5yJ5IDKm", filename, None), &library),
+                    Err(PackError::InvalidFileName(_))
+                ),
+                "{:?} was accepted",
+                filename
+            );
+        }
+
+        let dir = std::env::temp_dir();
+        for path in ["../config", "/etc", "config/../.."] {
+            let pack = MrpackBuilder::new("pack", "1.0.0")
+                .minecraft("1.20.1")
+                .overrides(path, dir.join("modrinth-check-unsafe-paths"));
+
+            assert!(
+                matches!(
+                    pack.write_to(dir.join("modrinth-check-unsafe-paths.mrpack")),
+                    Err(PackError::InvalidOverridePath(_))
+                ),
+                "{:?} was accepted",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn missing_project() {
+        let pack = MrpackBuilder::new("pack", "1.0.0")
+            .minecraft("1.20.1")
+            .versions(&[locked("5yJ5IDKm", "root.jar", None)]);
+
+        assert!(matches!(pack.index(), Err(PackError::MissingProject(_))));
+    }
+
+    #[test]
+    fn unsupported_loader() {
+        let pack = MrpackBuilder::new("pack", "1.0.0")
+            .minecraft("1.20.1")
+            .loader(Loader::Spigot, "1.0.0");
+
+        assert!(matches!(
+            pack.index(),
+            Err(PackError::UnsupportedLoader(Loader::Spigot))
+        ));
+    }
+
+    #[test]
+    fn write_pack() {
+        let dir = std::env::temp_dir().join("modrinth-check-write-pack");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("config")).unwrap();
+        fs::write(dir.join("config").join("mod.toml"), "enabled = true").unwrap();
+
+        let lock = [
+            locked("P7dR8mSH", "optional.jar", Some(DependencyType::Optional)),
+            locked("5yJ5IDKm", "root.zip", None),
+        ];
+        let path = dir.join("pack.mrpack");
+        let mut pack = MrpackBuilder::new("pack", "1.0.0")
+            .summary("A test pack")
+            .minecraft("1.20.1")
+            .loader(Loader::Fabric, "0.15.11")
+            .versions(&lock)
+            .overrides("config", dir.join("config"));
+        pack.projects.insert(
+            lock[0].project_id.clone(),
+            project(
+                ProjectType::Mod,
+                ModRequirement::Required,
+                ModRequirement::Required,
+            ),
+        );
+        pack.projects.insert(
+            lock[1].project_id.clone(),
+            project(
+                ProjectType::Resourcepack,
+                ModRequirement::Required,
+                ModRequirement::Unsupported,
+            ),
+        );
+        pack.write_to(&path).unwrap();
+
+        let mut archive = ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let index: PackIndex =
+            serde_json::from_reader(archive.by_name(INDEX_FILE).unwrap()).unwrap();
+
+        let mut config = String::new();
+        archive
+            .by_name("overrides/config/mod.toml")
+            .unwrap()
+            .read_to_string(&mut config)
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(config, "enabled = true");
+        assert_eq!(index.format_version, 1);
+        assert_eq!(&*index.dependencies["minecraft"], "1.20.1");
+        assert_eq!(&*index.dependencies["fabric-loader"], "0.15.11");
+        assert_eq!(index.files.len(), 2);
+        assert_eq!(&*index.files[0].path, "mods/optional.jar");
+        assert_eq!(
+            index.files[0].env.as_ref().unwrap().server,
+            PackRequirement::Optional
+        );
+        assert_eq!(&*index.files[1].path, "resourcepacks/root.zip");
+        assert_eq!(
+            index.files[1].env.as_ref().unwrap().server,
+            PackRequirement::Unsupported
+        );
+    }
+}
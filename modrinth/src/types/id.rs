@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Returned when a string isn't a valid ID or `Slug`
+pub struct InvalidId {
+    /// What the string was supposed to be
+    pub kind: &'static str,
+    /// The string that failed validation
+    pub value: String,
+}
+
+impl Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid {}", self.value, self.kind)
+    }
+}
+
+impl std::error::Error for InvalidId {}
+
+fn is_base62(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Follows Modrinth's slug regex: `^[\w!@$()`.+,"\-']{3,64}$`
+fn is_slug(value: &str) -> bool {
+    (3..=64).contains(&value.chars().count())
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_!@$()`.+,\"-'".contains(c))
+}
+
+/// Strings are only validated when built through `TryFrom` or `FromStr`.
+/// Whatever Modrinth sends is taken as-is, so that one odd value
+/// can't make a whole page of results fail to deserialize
+macro_rules! validated_str {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $validate:ident) => {
+        $(#[$meta])*
+        ///
+        /// Only checked when parsed with `FromStr` or `TryFrom`.
+        /// Deserializing takes any string, so IDs read from
+        /// Modrinth's responses or from files may be malformed
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(Arc<str>);
+
+        impl TryFrom<&str> for $name {
+            type Error = InvalidId;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                if $validate(value) {
+                    Ok(Self(value.into()))
+                } else {
+                    Err(InvalidId {
+                        kind: $kind,
+                        value: value.to_string(),
+                    })
+                }
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = InvalidId;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidId;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(s)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<$name> for Arc<str> {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                &*self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                &*self.0 == *other
+            }
+        }
+    };
+}
+
+validated_str!(
+    /// The ID of a project, which Modrinth encodes in base62
+    ProjectId,
+    "project id",
+    is_base62
+);
+
+validated_str!(
+    /// The ID of a version, which Modrinth encodes in base62
+    VersionId,
+    "version id",
+    is_base62
+);
+
+validated_str!(
+    /// The ID of a user, which Modrinth encodes in base62
+    UserId,
+    "user id",
+    is_base62
+);
+
+validated_str!(
+    /// The ID of the team owning a project, which Modrinth encodes in base62
+    TeamId,
+    "team id",
+    is_base62
+);
+
+validated_str!(
+    /// The slug of a project, used for vanity URLs
+    Slug,
+    "slug",
+    is_slug
+);
+
+#[cfg(feature = "api")]
+impl super::ModrinthProjectMeta for ProjectId {
+    type Id = Arc<str>;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(feature = "api")]
+impl super::ModrinthProjectMeta for Slug {
+    type Id = Arc<str>;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(feature = "api")]
+impl super::ModrinthProjectMeta for VersionId {
+    type Id = Arc<str>;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        None
+    }
+
+    fn version_id(&self) -> Option<Self::Id> {
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn valid_ids() {
+        assert_eq!(ProjectId::try_from("5yJ5IDKm").unwrap(), "5yJ5IDKm");
+        assert_eq!("AANobbMI".parse::<VersionId>().unwrap(), "AANobbMI");
+        assert_eq!(Slug::try_from("sodium-extra").unwrap(), "sodium-extra");
+    }
+
+    #[test]
+    fn invalid_ids() {
+        assert!(ProjectId::try_from("").is_err());
+        assert!(ProjectId::try_from("5yJ5-IDKm").is_err());
+        assert!(VersionId::try_from("AANobbMI?").is_err());
+        assert!(Slug::try_from("so").is_err());
+        assert!(Slug::try_from("sodium extra").is_err());
+    }
+
+    #[test]
+    fn deserialize_ids() {
+        let ids: Vec<ProjectId> = from_str(r#"["5yJ5IDKm", "AANobbMI"]"#).unwrap();
+        assert_eq!(ids.len(), 2);
+
+        // Modrinth isn't second-guessed, only constructors validate
+        let slug: Slug = from_str(r#""a""#).unwrap();
+        assert_eq!(slug, "a");
+        let id: ProjectId = from_str(r#""not/an/id""#).unwrap();
+        assert_eq!(id, "not/an/id");
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""not/an/id""#);
+    }
+}
@@ -1,8 +1,12 @@
 #![allow(clippy::ptr_arg)]
 #[cfg(feature = "types")]
 use serde::{Deserialize, Serialize, Serializer};
-use std::rc::Rc;
+use std::sync::Arc;
 
+#[cfg(feature = "types")]
+pub mod id;
+#[cfg(feature = "types")]
+pub mod notification;
 #[cfg(feature = "types")]
 pub mod project;
 #[cfg(feature = "types")]
@@ -10,12 +14,21 @@ pub mod query;
 #[cfg(feature = "types")]
 pub mod result;
 #[cfg(feature = "types")]
+pub mod tag;
+#[cfg(feature = "types")]
+pub mod user;
+#[cfg(feature = "types")]
 pub mod version;
 
 #[cfg(feature = "types")]
-pub use query::{Facet, FacetOp};
+pub use id::{InvalidId, ProjectId, Slug, TeamId, UserId, VersionId};
+#[cfg(feature = "types")]
+pub use query::{Facet, FacetOp, Facets};
 
 #[cfg(feature = "types")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+#[cfg(feature = "api")]
 pub(crate) trait ModrinthProjectMeta {
     type Id;
 
@@ -25,26 +38,72 @@ pub(crate) trait ModrinthProjectMeta {
     }
 }
 
+#[cfg(feature = "api")]
+impl<M: ModrinthProjectMeta + ?Sized> ModrinthProjectMeta for &M {
+    type Id = M::Id;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        (*self).project_id()
+    }
+
+    fn version_id(&self) -> Option<Self::Id> {
+        (*self).version_id()
+    }
+}
+
+#[cfg(feature = "api")]
+impl<M: ModrinthProjectMeta + ?Sized> ModrinthProjectMeta for Arc<M> {
+    type Id = M::Id;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        self.as_ref().project_id()
+    }
+
+    fn version_id(&self) -> Option<Self::Id> {
+        self.as_ref().version_id()
+    }
+}
+
+#[cfg(feature = "api")]
+/// A bare slug or project ID. Versions have to be
+/// identified with a `VersionId` instead
+impl ModrinthProjectMeta for str {
+    type Id = Arc<str>;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        Some(self.into())
+    }
+}
+
+#[cfg(feature = "api")]
+impl ModrinthProjectMeta for String {
+    type Id = Arc<str>;
+
+    fn project_id(&self) -> Option<Self::Id> {
+        self.as_str().project_id()
+    }
+}
+
 #[cfg(feature = "types")]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 /// The license of a project. Can be
 /// a `Single` or `Detailed` license
 pub enum License {
     /// License Type
-    Single(Rc<str>),
+    Single(Arc<str>),
     Detailed {
         /// License ID
-        id: Rc<str>,
+        id: Arc<str>,
         /// License pretty name
-        name: Rc<str>,
+        name: Arc<str>,
         /// URL where the license can be found
-        url: Option<Rc<str>>,
+        url: Option<Arc<str>>,
     },
 }
 
 #[cfg(feature = "types")]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ModRequirement {
     Optional,
@@ -53,6 +112,18 @@ pub enum ModRequirement {
     Unknown,
 }
 
+impl ToString for ModRequirement {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Optional => "optional",
+            Self::Required => "required",
+            Self::Unsupported => "unsupported",
+            Self::Unknown => "unknown",
+        }
+        .to_string()
+    }
+}
+
 #[cfg(feature = "types")]
 #[derive(Debug, Serialize, Default, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -67,9 +138,11 @@ pub enum IndexBy {
 }
 
 #[cfg(feature = "types")]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
-/// The loaders Modrinth supports
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(from = "String")]
+/// The loaders Modrinth supports. Loaders this crate doesn't
+/// know about yet are kept as `Other`, see `get_loaders`
+/// for the full list
 pub enum Loader {
     Bukkit,
     Bungeecord,
@@ -92,6 +165,42 @@ pub enum Loader {
     Vanilla,
     Velocity,
     Waterfall,
+    Other(Arc<str>),
+}
+
+impl From<&str> for Loader {
+    fn from(loader: &str) -> Self {
+        match loader {
+            "bukkit" => Self::Bukkit,
+            "bungeecord" => Self::Bungeecord,
+            "canvas" => Self::Canvas,
+            "datapack" => Self::Datapack,
+            "fabric" => Self::Fabric,
+            "folia" => Self::Folia,
+            "forge" => Self::Forge,
+            "iris" => Self::Iris,
+            "liteloader" => Self::Liteloader,
+            "minecraft" => Self::Minecraft,
+            "modloader" => Self::Modloader,
+            "neoforge" => Self::Neoforge,
+            "optifine" => Self::Optifine,
+            "purpur" => Self::Purpur,
+            "quilt" => Self::Quilt,
+            "rift" => Self::Rift,
+            "spigot" => Self::Spigot,
+            "sponge" => Self::Sponge,
+            "vanilla" => Self::Vanilla,
+            "velocity" => Self::Velocity,
+            "waterfall" => Self::Waterfall,
+            other => Self::Other(other.into()),
+        }
+    }
+}
+
+impl From<String> for Loader {
+    fn from(loader: String) -> Self {
+        Self::from(loader.as_str())
+    }
 }
 
 impl ToString for Loader {
@@ -118,6 +227,7 @@ impl ToString for Loader {
             Self::Vanilla => "vanilla",
             Self::Velocity => "velocity",
             Self::Waterfall => "waterfall",
+            Self::Other(other) => other,
         }
         .to_string()
     }
@@ -133,7 +243,7 @@ impl Serialize for Loader {
 }
 
 #[cfg(feature = "types")]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectType {
     Mod,
@@ -164,11 +274,11 @@ impl Serialize for ProjectType {
 }
 
 #[cfg(feature = "types")]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum Gallery {
-    Single(Rc<str>),
-    Multiple(Vec<Rc<str>>),
+    Single(Arc<str>),
+    Multiple(Vec<Arc<str>>),
 }
 
 pub(crate) fn serialize_vec_urlencoded<S, T>(vec: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
@@ -200,7 +310,7 @@ where
     serializer.serialize_str(&vec_vec_str)
 }
 
-fn serialize_vec<T>(vec: &Vec<T>) -> String
+pub(crate) fn serialize_vec<T>(vec: &Vec<T>) -> String
 where
     T: ToString,
 {
@@ -213,7 +323,143 @@ where
     )
 }
 
-#[allow(clippy::trivially_copy_pass_by_ref)]
 pub(in crate::types) fn is_zero(num: &u8) -> bool {
     *num == 0
 }
+
+#[cfg(test)]
+#[cfg(feature = "types")]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn known_loader() {
+        let loader: Loader = from_str(r#""neoforge""#).unwrap();
+
+        assert_eq!(loader, Loader::Neoforge);
+        assert_eq!(loader.to_string(), "neoforge");
+    }
+
+    #[test]
+    fn unknown_loader() {
+        let loaders: Vec<Loader> = from_str(r#"["fabric", "babric"]"#).unwrap();
+
+        assert_eq!(
+            loaders,
+            vec![Loader::Fabric, Loader::Other("babric".into())]
+        );
+        assert_eq!(loaders[1].to_string(), "babric");
+    }
+
+    const VERSION: &str = r#"{
+        "name": "Kontraption 1.2.0",
+        "version_number": "1.2.0",
+        "changelog": null,
+        "dependencies": [
+            {
+                "version_id": null,
+                "project_id": "P7dR8mSH",
+                "file_name": null,
+                "dependency_type": "required"
+            }
+        ],
+        "game_versions": ["1.20.1"],
+        "version_type": "release",
+        "loaders": ["forge"],
+        "featured": true,
+        "id": "AANobbMI",
+        "project_id": "5yJ5IDKm",
+        "author_id": "TEZXhE2U",
+        "date_published": "2023-08-01T12:00:00Z",
+        "downloads": 1200,
+        "files": [
+            {
+                "hashes": { "sha512": "abc", "sha1": "def" },
+                "url": "https://cdn.modrinth.com/data/5yJ5IDKm/versions/AANobbMI/kontraption.jar",
+                "filename": "kontraption.jar",
+                "primary": true,
+                "size": 1024,
+                "file_type": null
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn resolved_dependencies_round_trip() {
+        let mut version: version::ModrinthProjectVersion = from_str(VERSION).unwrap();
+        let dependency = version::ResolvedVersionDependency {
+            dependency: version.clone(),
+            dependency_type: version::DependencyType::Required,
+        };
+        version.dependencies = Some(vec![version::VersionDependency::Resolved(dependency)]);
+
+        let json = serde_json::to_string(&version).unwrap();
+        let replayed: version::ModrinthProjectVersion = from_str(&json).unwrap();
+
+        assert_eq!(serde_json::to_string(&replayed).unwrap(), json);
+
+        let dependencies = replayed.dependencies.unwrap();
+        let version::VersionDependency::Resolved(resolved) = &dependencies[0] else {
+            panic!("dependency was not replayed as resolved");
+        };
+        assert_eq!(resolved.dependency.id, "AANobbMI");
+        assert!(resolved.dependency.dependencies.as_ref().unwrap()[0].is_unresolved());
+    }
+
+    #[test]
+    fn locked_versions_round_trip() {
+        let version: version::ModrinthProjectVersion = from_str(VERSION).unwrap();
+        let locked = version::LockedVersion {
+            project_id: version.project_id.clone(),
+            version_id: version.id.clone(),
+            name: version.name.clone(),
+            version_number: version.version_number.clone(),
+            dependency_type: Some(version::DependencyType::Optional),
+            file: version.files[0].clone(),
+        };
+
+        let json = serde_json::to_string(&locked).unwrap();
+        let replayed: version::LockedVersion = from_str(&json).unwrap();
+
+        assert_eq!(replayed.version_id, locked.version_id);
+        assert_eq!(replayed.dependency_type, locked.dependency_type);
+        assert_eq!(replayed.file, locked.file);
+    }
+
+    #[test]
+    fn notifications() {
+        let notification: notification::Notification = from_str(
+            r#"{
+                "id": "UJzv6CPt",
+                "user_id": "TEZXhE2U",
+                "type": "project_update",
+                "title": "**Kontraption** has been updated!",
+                "text": "The project Kontraption has released a new version: 1.2.0",
+                "link": "/mod/5yJ5IDKm/version/AANobbMI",
+                "read": false,
+                "created": "2023-08-01T12:00:00.000000Z",
+                "actions": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            notification.notification_type,
+            Some(notification::NotificationType::ProjectUpdate)
+        );
+
+        let unknown: notification::NotificationType = from_str(r#""payout_available""#).unwrap();
+        assert_eq!(unknown, notification::NotificationType::Unknown);
+    }
+
+    #[test]
+    fn models_are_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<project::ModrinthProject>();
+        assert_send_sync::<version::ModrinthProjectVersion>();
+        assert_send_sync::<version::LockedVersion>();
+        assert_send_sync::<result::SearchProjectResult>();
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::{DateTime, UserId};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+/// Represents a notification sent to a Modrinth user.
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/notification_model)*
+pub struct Notification {
+    /// The ID of the notification
+    pub id: Arc<str>,
+    /// The ID of the user who received the notification
+    pub user_id: UserId,
+    /// The type of notification
+    #[serde(rename = "type")]
+    pub notification_type: Option<NotificationType>,
+    /// The title of the notification
+    pub title: Arc<str>,
+    /// The body text of the notification
+    pub text: Arc<str>,
+    /// A link to the related project or version
+    pub link: Arc<str>,
+    /// Whether the notification has been read or not
+    pub read: bool,
+    /// The time at which the notification was created
+    pub created: DateTime,
+    /// A list of actions that can be performed
+    pub actions: Vec<NotificationAction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    ProjectUpdate,
+    TeamInvite,
+    StatusChange,
+    ModeratorMessage,
+    /// A type of notification this crate doesn't know about yet
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+/// An action that can be performed on a notification
+pub struct NotificationAction {
+    /// The friendly name for this action
+    pub title: Arc<str>,
+    /// The HTTP method and path of the API call to perform the action
+    pub action_route: (Arc<str>, Arc<str>),
+}
@@ -1,95 +1,107 @@
-use serde::Deserialize;
-use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
-use super::{Loader, ModRequirement, ProjectType};
+use super::{DateTime, Loader, ModRequirement, ProjectId, ProjectType, Slug, TeamId, VersionId};
+use crate::ModrinthProjectVersion;
 
 // TODO: Add serde_valid (optional?)
 //       https://docs.rs/serde_valid/latest/serde_valid/
 /// Represents 1 project hosted on Modrinth
 /// *The documentation for the fields of this struct*
 /// *have been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/project_model)*
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ModrinthProject {
     /// The slug of a project, used for vanity URLs. Regex: `^[\w!@$()`.+,"\-']{3,64}$`
-    pub slug: Rc<str>,
+    pub slug: Slug,
     /// The title or name of the project
-    pub title: Rc<str>,
+    pub title: Arc<str>,
     /// A short description of the project
-    pub description: Rc<str>,
+    pub description: Arc<str>,
     /// A list of the categories that the project has
-    pub categories: Vec<Rc<str>>,
+    pub categories: Vec<Arc<str>>,
     /// The client side support of the project
     pub client_side: ModRequirement,
     /// The client side support of the project
     pub server_side: ModRequirement,
     /// A long form description of the project
-    pub body: Rc<str>,
+    pub body: Arc<str>,
     /// The status of the project
     pub status: Status,
 
     /// A list of categories which are searchable but non-primary
-    pub additional_categories: Option<Vec<Rc<str>>>,
+    pub additional_categories: Option<Vec<Arc<str>>>,
     /// An optional link to where to submit bugs or issues with the project
-    pub issues_url: Option<Rc<str>>,
+    pub issues_url: Option<Arc<str>>,
     /// An optional link to the source code of the project
-    pub source_url: Option<Rc<str>>,
+    pub source_url: Option<Arc<str>>,
     /// An optional link to the project's wiki page or other relevant information
-    pub wiki_url: Option<Rc<str>>,
+    pub wiki_url: Option<Arc<str>>,
     /// An optional invite link to the project's discord
-    pub discord_url: Option<Rc<str>>,
+    pub discord_url: Option<Arc<str>>,
 
     /// The project type of the project
     pub project_type: ProjectType,
     /// The URL of the project's icon
-    pub icon_url: Option<Rc<str>>,
+    pub icon_url: Option<Arc<str>>,
 
     /// The RGB color of the project, automatically generated from the project icon
     pub color: Option<u32>,
 
-    /// The ID of the project, encoded as a base62 Rc<str>
-    pub id: Rc<str>,
+    /// The ID of the project, encoded as a base62 string
+    pub id: ProjectId,
     /// The ID of the team that has ownership of this project
-    pub team: Rc<str>,
+    pub team: TeamId,
 
     /// The date the project was published
-    pub published: Rc<str>,
+    pub published: DateTime,
     /// The date the project was last updated
-    pub updated: Rc<str>,
+    pub updated: DateTime,
 
     /// A list of the version IDs of the project (will never be empty unless `draft` status)
-    pub versions: Vec<Rc<str>>,
+    pub versions: Vec<VersionId>,
     /// A list of all of the game versions supported by the project
-    pub game_versions: Vec<Rc<str>>,
+    pub game_versions: Vec<Arc<str>>,
     /// A list of all of the loaders supported by the project
-    pub loaders: Rc<[Loader]>,
+    pub loaders: Arc<[Loader]>,
     /// A list of images that have been uploaded to the project's gallery
-    pub gallery: Option<Rc<[GalleryEntry]>>,
+    pub gallery: Option<Arc<[GalleryEntry]>>,
 }
 
+#[cfg(feature = "api")]
 impl super::ModrinthProjectMeta for ModrinthProject {
-    type Id = Rc<str>;
+    type Id = Arc<str>;
 
     fn project_id(&self) -> Option<Self::Id> {
-        Some(self.id.clone())
+        Some(self.id.clone().into())
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents everything the versions of a project depend on,
+/// as returned by `get_project_dependencies`
+pub struct ProjectDependencies {
+    /// Projects the versions depend on
+    pub projects: Vec<ModrinthProject>,
+    /// Specific versions the versions depend on
+    pub versions: Vec<ModrinthProjectVersion>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 /// Represents an image in a gallery
 pub struct GalleryEntry {
     /// The URL of the image
-    pub url: Rc<str>,
+    pub url: Arc<str>,
     /// The image's title
-    pub title: Option<Rc<str>>,
+    pub title: Option<Arc<str>>,
     /// The image's description
-    pub description: Option<Rc<str>>,
+    pub description: Option<Arc<str>>,
     /// When the image was uploaded
-    pub created: Rc<str>,
+    pub created: DateTime,
     /// What order/index the image should be at
     pub ordering: Option<u8>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     Approved,
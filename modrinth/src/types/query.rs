@@ -2,9 +2,11 @@ mod facets;
 mod query;
 mod version;
 
+#[cfg(feature = "api")]
 pub(crate) use query::ProjectQuery;
+#[cfg(feature = "api")]
 pub(crate) use version::VersionQuery;
 
-pub use facets::{Facet, FacetOp};
+pub use facets::{Facet, FacetOp, Facets};
 pub use query::ProjectQueryBuilder;
 pub use version::VersionQueryBuilder;
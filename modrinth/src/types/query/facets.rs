@@ -1,12 +1,19 @@
 use crate::types::Loader;
+use crate::types::ModRequirement;
 use crate::types::ProjectType;
 use serde::Serialize;
+use std::ops::Not;
 
 #[derive(Debug)]
 /// Represents the facets used by
 /// Modrinth's Search API to filter
 /// results.
 ///
+/// Facets that compare numbers carry
+/// their own `FacetOp`, every other
+/// facet checks for equality and can
+/// be flipped with `!`.
+///
 /// More information on how this,
 /// and the `Custom` variant works
 /// can be found [here](https://docs.modrinth.com/#tag/projects)
@@ -19,10 +26,32 @@ pub enum Facet {
     Loader(Loader),
     /// Project must be supported by minecraft version...
     Version(String),
+    /// Project's client side support must be...
+    ClientSide(ModRequirement),
+    /// Project's server side support must be...
+    ServerSide(ModRequirement),
     /// Project must be open source?
     OpenSource(bool),
+    /// Project must be titled...
+    Title(String),
+    /// Project must be authored by...
+    Author(String),
+    /// Project must have the ID...
+    ProjectId(String),
     /// Project must be licensed under...
     License(String),
+    /// Project's download count must be...
+    Downloads(FacetOp, u64),
+    /// Project's follower count must be...
+    Follows(FacetOp, u64),
+    /// Project's color must be...
+    Color(FacetOp, u32),
+    /// Project must have been created at the UNIX timestamp...
+    CreatedTimestamp(FacetOp, i64),
+    /// Project must have been last modified at the UNIX timestamp...
+    ModifiedTimestamp(FacetOp, i64),
+    /// Project must not match...
+    Not(Box<Facet>),
     Custom {
         _type: String,
         op: FacetOp,
@@ -30,16 +59,67 @@ pub enum Facet {
     },
 }
 
+impl Facet {
+    /// The name, operation and value of this facet
+    fn parts(&self) -> (&str, FacetOp, String) {
+        match self {
+            Self::ProjectType(project_type) => {
+                ("project_type", FacetOp::Equal, project_type.to_string())
+            }
+            Self::Category(category) => ("categories", FacetOp::Equal, category.clone()),
+            Self::Loader(loader) => ("categories", FacetOp::Equal, loader.to_string()),
+            Self::Version(version) => ("versions", FacetOp::Equal, version.clone()),
+            Self::ClientSide(requirement) => {
+                ("client_side", FacetOp::Equal, requirement.to_string())
+            }
+            Self::ServerSide(requirement) => {
+                ("server_side", FacetOp::Equal, requirement.to_string())
+            }
+            Self::OpenSource(open_source) => {
+                ("open_source", FacetOp::Equal, open_source.to_string())
+            }
+            Self::Title(title) => ("title", FacetOp::Equal, title.clone()),
+            Self::Author(author) => ("author", FacetOp::Equal, author.clone()),
+            Self::ProjectId(project_id) => ("project_id", FacetOp::Equal, project_id.clone()),
+            Self::License(license) => ("license", FacetOp::Equal, license.clone()),
+            Self::Downloads(op, downloads) => ("downloads", *op, downloads.to_string()),
+            Self::Follows(op, follows) => ("follows", *op, follows.to_string()),
+            Self::Color(op, color) => ("color", *op, color.to_string()),
+            Self::CreatedTimestamp(op, timestamp) => {
+                ("created_timestamp", *op, timestamp.to_string())
+            }
+            Self::ModifiedTimestamp(op, timestamp) => {
+                ("modified_timestamp", *op, timestamp.to_string())
+            }
+            Self::Not(facet) => {
+                let (_type, op, value) = facet.parts();
+                (_type, op.negate(), value)
+            }
+            Self::Custom { _type, op, value } => (_type, *op, value.clone()),
+        }
+    }
+}
+
+impl Not for Facet {
+    type Output = Self;
+
+    /// Flips this facet, so that it matches
+    /// every project it didn't match before
+    fn not(self) -> Self {
+        match self {
+            Self::Not(facet) => *facet,
+            facet => Self::Not(Box::new(facet)),
+        }
+    }
+}
+
 impl ToString for Facet {
     fn to_string(&self) -> String {
-        match self {
-            Self::ProjectType(project_type) => format!("project_type:{}", project_type.to_string()),
-            Self::Category(category) => format!("categories:{}", category),
-            Self::Loader(loader) => format!("categories:{}", loader.to_string()),
-            Self::Version(version) => format!("version:{}", version),
-            Self::OpenSource(open_source) => format!("open_source:{}", open_source),
-            Self::License(license) => format!("license:{}", license),
-            Self::Custom { _type, op, value } => format!("{}{}{}", _type, op.to_string(), value),
+        let (_type, op, value) = self.parts();
+
+        match op {
+            FacetOp::Equal => format!("{}:{}", _type, value),
+            op => format!("{}{}{}", _type, op.to_string(), value),
         }
     }
 }
@@ -55,7 +135,7 @@ impl Serialize for Facet {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FacetOp {
     Equal,        // =
     NotEqual,     // !=
@@ -65,6 +145,20 @@ pub enum FacetOp {
     LesserThan,   //  <
 }
 
+impl FacetOp {
+    /// The operation matching every value this one doesn't
+    pub fn negate(self) -> Self {
+        match self {
+            Self::Equal => Self::NotEqual,
+            Self::NotEqual => Self::Equal,
+            Self::GreaterEqual => Self::LesserThan,
+            Self::GreaterThan => Self::LesserEqual,
+            Self::LesserEqual => Self::GreaterThan,
+            Self::LesserThan => Self::GreaterEqual,
+        }
+    }
+}
+
 impl ToString for FacetOp {
     fn to_string(&self) -> String {
         match self {
@@ -78,3 +172,143 @@ impl ToString for FacetOp {
         .to_string()
     }
 }
+
+#[derive(Debug, Default)]
+/// Combines facets for `ProjectQueryBuilder::facets`.
+///
+/// Every call to `and` or `and_any` adds a group that
+/// projects must match on top of the previous ones.
+/// ## Usage
+/// ```
+/// use modrinth::{Facet, FacetOp, Facets, Loader, ProjectQueryBuilder};
+///
+/// // Forge OR Fabric mods, AND with at least 1000 downloads
+/// let facets = Facets::new()
+///     .and_any(vec![Facet::Loader(Loader::Forge), Facet::Loader(Loader::Fabric)])
+///     .and(Facet::Downloads(FacetOp::GreaterEqual, 1000));
+///
+/// let query = ProjectQueryBuilder::new().facets(facets).build();
+/// ```
+pub struct Facets {
+    groups: Vec<Vec<Facet>>,
+}
+
+impl Facets {
+    /// Creates an empty set of facets
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Projects must also match `facet`
+    pub fn and(self, facet: Facet) -> Self {
+        self.and_any(vec![facet])
+    }
+
+    /// Projects must also match at least one of `facets`
+    pub fn and_any(mut self, facets: Vec<Facet>) -> Self {
+        if !facets.is_empty() {
+            self.groups.push(facets);
+        }
+
+        self
+    }
+}
+
+impl From<Facets> for Vec<Vec<Facet>> {
+    fn from(facets: Facets) -> Self {
+        facets.groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality_facets() {
+        let facets = [
+            (Facet::ProjectType(ProjectType::Mod), "project_type:mod"),
+            (Facet::Category("adventure".into()), "categories:adventure"),
+            (Facet::Loader(Loader::Forge), "categories:forge"),
+            (Facet::Version("1.20.1".into()), "versions:1.20.1"),
+            (
+                Facet::ClientSide(ModRequirement::Required),
+                "client_side:required",
+            ),
+            (
+                Facet::ServerSide(ModRequirement::Unsupported),
+                "server_side:unsupported",
+            ),
+            (Facet::OpenSource(true), "open_source:true"),
+            (Facet::Title("Sodium".into()), "title:Sodium"),
+            (Facet::Author("jellysquid3".into()), "author:jellysquid3"),
+            (Facet::ProjectId("AANobbMI".into()), "project_id:AANobbMI"),
+            (Facet::License("MIT".into()), "license:MIT"),
+        ];
+
+        for (facet, expected) in facets {
+            assert_eq!(facet.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn comparison_facets() {
+        let facets = [
+            (
+                Facet::Downloads(FacetOp::GreaterEqual, 1000),
+                "downloads>=1000",
+            ),
+            (Facet::Follows(FacetOp::LesserThan, 10), "follows<10"),
+            (Facet::Color(FacetOp::Equal, 1234), "color:1234"),
+            (
+                Facet::CreatedTimestamp(FacetOp::GreaterThan, 1700000000),
+                "created_timestamp>1700000000",
+            ),
+            (
+                Facet::ModifiedTimestamp(FacetOp::LesserEqual, 1700000000),
+                "modified_timestamp<=1700000000",
+            ),
+            (
+                Facet::Custom {
+                    _type: "slug".into(),
+                    op: FacetOp::NotEqual,
+                    value: "sodium".into(),
+                },
+                "slug!=sodium",
+            ),
+        ];
+
+        for (facet, expected) in facets {
+            assert_eq!(facet.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn negated_facets() {
+        assert_eq!(
+            (!Facet::Version("1.20.1".into())).to_string(),
+            "versions!=1.20.1"
+        );
+        assert_eq!(
+            (!Facet::Downloads(FacetOp::GreaterEqual, 100)).to_string(),
+            "downloads<100"
+        );
+        assert_eq!((!!Facet::OpenSource(true)).to_string(), "open_source:true");
+    }
+
+    #[test]
+    fn facet_groups() {
+        let facets: Vec<Vec<Facet>> = Facets::new()
+            .and_any(vec![
+                Facet::Version("1.16.5".into()),
+                Facet::Version("1.17.1".into()),
+            ])
+            .and(Facet::ProjectType(ProjectType::Modpack))
+            .and_any(Vec::new())
+            .into();
+
+        assert_eq!(facets.len(), 2);
+        assert_eq!(facets[0].len(), 2);
+        assert_eq!(facets[1].len(), 1);
+    }
+}
@@ -84,8 +84,10 @@ impl ProjectQueryBuilder {
     ///
     /// Separate arrays are considered to be joined by AND statements.
     /// For example, the search `[["versions:1.16.5"], ["project_type:modpack"]]` translates to *Projects that support 1.16.5 AND are modpacks*.
-    pub fn facets(mut self, facets: Vec<Vec<Facet>>) -> Self {
-        self.facets = Some(facets);
+    ///
+    /// `Facets` can be used to build these arrays.
+    pub fn facets<F: Into<Vec<Vec<Facet>>>>(mut self, facets: F) -> Self {
+        self.facets = Some(facets.into());
         self
     }
 
@@ -123,3 +125,44 @@ impl ProjectQueryBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Facets, Loader, ProjectType};
+    use serde_urlencoded::{from_str, to_string};
+
+    #[test]
+    fn facets_serialization() {
+        let query = ProjectQueryBuilder::new()
+            .query("gravestones")
+            .facets(
+                Facets::new()
+                    .and_any(vec![
+                        Facet::Loader(Loader::Forge),
+                        Facet::Loader(Loader::Fabric),
+                    ])
+                    .and(Facet::Version("1.20.1".to_string()))
+                    .and(!Facet::ProjectType(ProjectType::Mod)),
+            )
+            .build();
+
+        let res = to_string(&query).unwrap();
+        let params: Vec<(String, String)> = from_str(&res).unwrap();
+        let (_, facets) = params.iter().find(|(key, _)| key == "facets").unwrap();
+
+        assert_eq!(
+            facets,
+            r#"[["categories:forge", "categories:fabric"], ["versions:1.20.1"], ["project_type!=mod"]]"#
+        );
+    }
+
+    #[test]
+    fn empty_facets_are_omitted() {
+        let query = ProjectQueryBuilder::new().query("gravestones").build();
+
+        let res = to_string(&query).unwrap();
+
+        assert_eq!(res, "query=gravestones&index=relevance&limit=10");
+    }
+}
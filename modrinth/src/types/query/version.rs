@@ -1,3 +1,4 @@
+use crate::types::version::VersionType;
 use crate::types::Loader;
 use serde::Serialize;
 
@@ -15,17 +16,34 @@ pub struct VersionQuery {
         serialize_with = "crate::types::serialize_vec_urlencoded"
     )]
     pub(crate) game_versions: Vec<String>,
-    pub(crate) featured: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) featured: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) version_type: Option<VersionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) include_changelog: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) offset: Option<u32>,
 }
 
 #[derive(Debug, Default)]
 /// Represents a complex search query for
 /// `get_versions`. Use `.build()` to build
-/// the query
+/// the query.
+///
+/// Options that are never set are left out
+/// of the request, so Modrinth applies its
+/// own defaults for them
 pub struct VersionQueryBuilder {
     pub loaders: Option<Vec<Loader>>,
     pub versions: Option<Vec<String>>,
     pub featured: Option<bool>,
+    pub version_type: Option<VersionType>,
+    pub include_changelog: Option<bool>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
 }
 
 impl VersionQueryBuilder {
@@ -46,18 +64,80 @@ impl VersionQueryBuilder {
         self
     }
 
-    /// Version has to be featured
+    /// Version has to be featured, or has to not be featured
     pub fn featured(mut self, featured: bool) -> Self {
         self.featured = Some(featured);
         self
     }
 
+    /// Version must be released on the channel...
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.version_type = Some(version_type);
+        self
+    }
+
+    /// Whether changelogs are sent along with the versions.
+    /// Leaving them out makes listings of projects with
+    /// many versions a lot smaller
+    pub fn include_changelog(mut self, include_changelog: bool) -> Self {
+        self.include_changelog = Some(include_changelog);
+        self
+    }
+
+    /// The number of versions returned
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The offset into the version list. Skips this number of versions
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Build the query
     pub fn build(self) -> VersionQuery {
         VersionQuery {
             loaders: self.loaders.unwrap_or_default(),
             game_versions: self.versions.unwrap_or_default(),
-            featured: self.featured.unwrap_or_default(),
+            featured: self.featured,
+            version_type: self.version_type,
+            include_changelog: self.include_changelog,
+            limit: self.limit,
+            offset: self.offset,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_urlencoded::to_string;
+
+    #[test]
+    fn absent_options_are_omitted() {
+        let query = VersionQueryBuilder::new().build();
+
+        assert_eq!(to_string(&query).unwrap(), "");
+    }
+
+    #[test]
+    fn full_query_serialization() {
+        let query = VersionQueryBuilder::new()
+            .loaders(vec![Loader::Fabric])
+            .versions(vec!["1.20.1"])
+            .featured(false)
+            .version_type(VersionType::Beta)
+            .include_changelog(false)
+            .limit(20)
+            .offset(40)
+            .build();
+
+        assert_eq!(
+            to_string(&query).unwrap(),
+            "loaders=%5B%22fabric%22%5D&game_versions=%5B%221.20.1%22%5D&featured=false\
+             &version_type=beta&include_changelog=false&limit=20&offset=40"
+        );
+    }
+}
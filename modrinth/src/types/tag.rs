@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::{DateTime, Loader};
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a category projects can be listed under
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/tags)*
+pub struct CategoryTag {
+    /// An SVG icon for the category
+    pub icon: Arc<str>,
+    /// The name of the category
+    pub name: Arc<str>,
+    /// The project type this category is applicable to
+    pub project_type: Arc<str>,
+    /// The header under which the category should go
+    pub header: Arc<str>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a loader projects can be loaded by
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/tags)*
+pub struct LoaderTag {
+    /// An SVG icon for the loader
+    pub icon: Arc<str>,
+    /// The name of the loader
+    pub name: Loader,
+    /// The project types that this loader is applicable to
+    pub supported_project_types: Vec<Arc<str>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a version of Minecraft
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/tags)*
+pub struct GameVersionTag {
+    /// The name/number of the game version
+    pub version: Arc<str>,
+    /// The type of the game version
+    pub version_type: GameVersionType,
+    /// The date of the game version release
+    pub date: DateTime,
+    /// Whether or not this is a major version, used for Featured Versions
+    pub major: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+/// The release channel of a version of Minecraft
+pub enum GameVersionType {
+    Release,
+    Snapshot,
+    Alpha,
+    Beta,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a license projects can be licensed under
+pub struct LicenseTag {
+    /// The short identifier of the license
+    pub short: Arc<str>,
+    /// The full name of the license
+    pub name: Arc<str>,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::{DateTime, TeamId, UserId};
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a Modrinth user.
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/user_model)*
+pub struct User {
+    /// The user's ID
+    pub id: UserId,
+    /// The user's username
+    pub username: Arc<str>,
+    /// The user's display name
+    pub name: Option<Arc<str>>,
+    /// The user's avatar url
+    pub avatar_url: Option<Arc<str>>,
+    /// A description of the user
+    pub bio: Option<Arc<str>>,
+    /// The time at which the user was created
+    pub created: DateTime,
+    /// The user's role
+    pub role: UserRole,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    Admin,
+    Moderator,
+    Developer,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+/// Represents a member of the team owning a project.
+///
+/// *The documentation for this struct's fields have*
+/// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/team_member_model)*
+pub struct TeamMember {
+    /// The ID of the team this team member is a member of
+    pub team_id: TeamId,
+    /// The user associated with this team member
+    pub user: User,
+    /// The user's role on the team
+    pub role: Arc<str>,
+    /// The user's permissions in bitfield format. Only visible to team members
+    pub permissions: Option<u64>,
+    /// Whether or not the user has accepted to be on the team
+    pub accepted: bool,
+    /// The split of payouts going to this user. Only visible to team members
+    pub payouts_split: Option<f64>,
+    /// The order of the team member
+    pub ordering: Option<i64>,
+}
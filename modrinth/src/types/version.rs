@@ -1,61 +1,87 @@
-use serde::Deserialize;
-use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
+use super::{DateTime, ProjectId, UserId, VersionId};
 use crate::Loader;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 /// Represents a version of a Modrinth project.
 ///
 /// *The documentation for this struct's fields have*
 /// *been copied over from [Modrinth's documentation](https://docs.modrinth.com/#tag/version_model)*
 pub struct ModrinthProjectVersion {
     /// The name of this version
-    pub name: Rc<str>,
+    pub name: Arc<str>,
     /// The version number. Ideally will follow semantic versioning
-    pub version_number: Rc<str>,
+    pub version_number: Arc<str>,
     /// The changelog for this version
-    pub changelog: Option<Rc<str>>,
+    pub changelog: Option<Arc<str>>,
     /// A list of specific versions of projects that this version depends on
     pub dependencies: Option<Vec<VersionDependency>>,
     /// The release channel for this version
-    pub game_versions: Vec<Rc<str>>,
+    pub game_versions: Vec<Arc<str>>,
     /// A list of versions of Minecraft that this version supports
     pub version_type: VersionType,
     /// The mod loaders that this version supports
     pub loaders: Option<Vec<Loader>>,
     /// Whether the version is featured or not
     pub featured: bool,
-    /// The ID of the version, encoded as a base62 Rc<str>
-    pub id: Rc<str>,
+    /// The ID of the version, encoded as a base62 string
+    pub id: VersionId,
     /// The ID of the project this version is for
-    pub project_id: Rc<str>,
+    pub project_id: ProjectId,
     /// The ID of the author who published this version
-    pub author_id: Rc<str>,
+    pub author_id: UserId,
     /// The date this version has been published
-    pub date_published: Rc<str>,
+    pub date_published: DateTime,
     /// The number of times this version has been downloaded
     pub downloads: usize,
     /// A list of files available for download for this version
     pub files: Vec<VersionFile>,
 }
 
-#[derive(Debug, Deserialize)]
+#[cfg(any(feature = "api", feature = "mrpack"))]
+/// Whether `name` is a single plain path component, so that
+/// joining it to a folder can't point outside of the folder
+pub(crate) fn is_plain_file_name(name: &str) -> bool {
+    let mut components = std::path::Path::new(name).components();
+
+    matches!(
+        (components.next(), components.next()),
+        (Some(std::path::Component::Normal(_)), None)
+    )
+}
+
+impl ModrinthProjectVersion {
+    /// The file marked as primary, falling back to the
+    /// first file listed if none of them are
+    pub fn primary_file(&self) -> Option<&VersionFile> {
+        self.files
+            .iter()
+            .find(|file| file.primary)
+            .or(self.files.first())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
+/// The release channel of a version
 pub enum VersionType {
     Release,
     Beta,
     Alpha,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(untagged)]
 /// Represents a dependency of a `ModrinthProjectVersion`
 pub enum VersionDependency {
-    /// The dependency has yet to be resolved
-    Unresolved(UnresolvedVersionDependency),
-    #[serde(skip)]
+    // Tried first, since every resolved dependency
+    // would also pass as an unresolved one
     /// The dependency has been resolved
     Resolved(ResolvedVersionDependency),
+    /// The dependency has yet to be resolved
+    Unresolved(UnresolvedVersionDependency),
 }
 
 impl VersionDependency {
@@ -73,32 +99,33 @@ impl VersionDependency {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 /// Represents a unresolved dependency of a `ModrinthProjectVersion`
 pub struct UnresolvedVersionDependency {
     /// The version id of the unresolved dependency
-    pub version_id: Option<Rc<str>>,
+    pub version_id: Option<VersionId>,
     /// The project id of the unresolved dependency
-    pub project_id: Option<Rc<str>>,
+    pub project_id: Option<ProjectId>,
     /// The file name of the unresolved dependency
-    pub file_name: Option<Rc<str>>,
+    pub file_name: Option<Arc<str>>,
     /// The requirement type (Required, Optional, etc.) of the unresolved dependency
     pub dependency_type: DependencyType,
 }
 
+#[cfg(feature = "api")]
 impl super::ModrinthProjectMeta for UnresolvedVersionDependency {
-    type Id = Rc<str>;
+    type Id = Arc<str>;
 
     fn project_id(&self) -> Option<Self::Id> {
-        self.project_id.clone()
+        self.project_id.clone().map(Arc::from)
     }
 
     fn version_id(&self) -> Option<Self::Id> {
-        self.version_id.clone()
+        self.version_id.clone().map(Arc::from)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 /// Represents a resolved dependency of a `ModrinthProjectVersion`
 pub struct ResolvedVersionDependency {
     /// the resolved project dependency
@@ -107,7 +134,7 @@ pub struct ResolvedVersionDependency {
     pub dependency_type: DependencyType,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 /// Represents the relationships a dependency can take
 pub enum DependencyType {
@@ -123,15 +150,15 @@ pub enum DependencyType {
     Embedded,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 /// Represents a file listed in the `.files` map
 pub struct VersionFile {
     /// Hashes of the file provided by Modrinth
     pub hashes: VersionFileHashes,
     /// URL pointing to the resource to download
-    pub url: Rc<str>,
+    pub url: Arc<str>,
     /// Name of the file
-    pub filename: Rc<str>,
+    pub filename: Arc<str>,
     /// Is the file a primary file
     pub primary: bool,
     /// Size of the file
@@ -140,15 +167,15 @@ pub struct VersionFile {
     pub file_type: Option<VersionFileType>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 pub struct VersionFileHashes {
     /// SHA512 hash of the file
-    pub sha512: Rc<str>,
+    pub sha512: Arc<str>,
     /// SHA1 hash of the file
-    pub sha1: Rc<str>,
+    pub sha1: Arc<str>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 /// Represents the relationships a non-dependency file can take
 pub enum VersionFileType {
     /// Non-dependency file is required
@@ -158,3 +185,23 @@ pub enum VersionFileType {
     #[serde(rename = "optional-resource-pack")]
     Optional,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+/// Represents one version to install, as listed by `resolve_dependencies`.
+/// Lock lists can be saved and loaded again later to install the same
+/// versions without asking Modrinth
+pub struct LockedVersion {
+    /// The ID of the project this version is for
+    pub project_id: ProjectId,
+    /// The ID of the version
+    pub version_id: VersionId,
+    /// The name of the version
+    pub name: Arc<str>,
+    /// The version number
+    pub version_number: Arc<str>,
+    /// How the version was pulled in, `None` for the version
+    /// dependencies were resolved for
+    pub dependency_type: Option<DependencyType>,
+    /// The file to download
+    pub file: VersionFile,
+}